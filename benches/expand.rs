@@ -0,0 +1,40 @@
+//! Expansion time of the derives on a struct of 50 fields without attributes.
+//!
+//! A `proc-macro` crate exports nothing but its derives, so the modules are built in here
+//! directly. They only use `proc_macro2`, which works outside of a macro.
+use std::convert::TryFrom;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use syn::DeriveInput;
+
+#[allow(dead_code, unused_imports)]
+#[path = "../src/faultmsg.rs"]
+mod faultmsg;
+#[allow(dead_code, unused_imports, clippy::needless_lifetimes)]
+#[path = "../src/extract.rs"]
+mod extract;
+#[allow(dead_code, unused_imports)]
+#[path = "../src/getters.rs"]
+mod getters;
+#[allow(dead_code, unused_imports)]
+#[path = "../src/dissolve.rs"]
+mod dissolve;
+
+fn plain_struct() -> DeriveInput {
+    let fields: Vec<String> = (0..50).map(|i| format!("field_{}: u64", i)).collect();
+    syn::parse_str(format!("struct Plain {{ {} }}", fields.join(", ")).as_str()).unwrap()
+}
+
+fn expand(c: &mut Criterion) {
+    let ast = plain_struct();
+
+    c.bench_function("getters 50 fields", |b| b.iter(|| {
+        getters::NamedStruct::try_from(black_box(&ast)).unwrap().emit()
+    }));
+    c.bench_function("dissolve 50 fields", |b| b.iter(|| {
+        dissolve::NamedStruct::try_from(black_box(&ast)).unwrap().emit()
+    }));
+}
+
+criterion_group!(benches, expand);
+criterion_main!(benches);
@@ -0,0 +1,102 @@
+//! AsMut internals
+use std::convert::TryFrom;
+
+use proc_macro2::{TokenStream, Span};
+use quote::quote;
+use syn::{
+    DeriveInput,
+    Member,
+    Index,
+    Type,
+    Ident,
+    Result,
+    Error,
+};
+
+use crate::{
+    extract::named_struct,
+    faultmsg::Problem,
+};
+
+/// A struct of one field, named or positional, wrapping the type it lends out.
+pub struct Wrapper<'a> {
+    original: &'a DeriveInput,
+    name: Ident,
+    ty: Type,
+    member: Member,
+}
+
+impl<'a> Wrapper<'a> {
+    pub fn emit(&self) -> TokenStream {
+        let (impl_generics, struct_generics, where_clause) = self.original.generics
+            .split_for_impl();
+        let struct_name = &self.name;
+        let inner = &self.ty;
+        let member = &self.member;
+
+        quote!(
+            impl #impl_generics ::core::convert::AsMut<#inner> for #struct_name #struct_generics
+                #where_clause
+            {
+                fn as_mut(&mut self) -> &mut #inner {
+                    &mut self.#member
+                }
+            }
+        )
+    }
+}
+
+impl<'a> TryFrom<&'a DeriveInput> for Wrapper<'a> {
+    type Error = Error;
+
+    fn try_from(node: &'a DeriveInput) -> Result<Self> {
+        let struct_data = named_struct(node)?;
+        let count = struct_data.fields.len();
+        let field = match struct_data.fields.iter().next() {
+            Some(field) if count == 1 => field,
+            _ => return Err(Error::new(Span::call_site(), Problem::NotSingleField(count))),
+        };
+        let member = match &field.ident {
+            Some(name) => Member::Named(name.clone()),
+            None => Member::Unnamed(Index::from(0)),
+        };
+
+        Ok(Wrapper {
+            original: node,
+            name: node.ident.clone(),
+            ty: field.ty.clone(),
+            member,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn emit_as_mut() -> Result<()> {
+        let tuple: DeriveInput = syn::parse_str("struct Meters(f64);")?;
+        let emitted = Wrapper::try_from(&tuple)?.emit().to_string();
+        assert!(emitted.contains(
+            "impl :: core :: convert :: AsMut < f64 > for Meters \
+             { fn as_mut (& mut self) -> & mut f64 { & mut self . 0 } }"
+        ));
+
+        let named: DeriveInput = syn::parse_str(
+            "struct Buffer<T> where T: Clone { items: Vec<T> }"
+        )?;
+        let emitted = Wrapper::try_from(&named)?.emit().to_string();
+        assert!(emitted.contains("impl < T > :: core :: convert :: AsMut < Vec < T > > for Buffer < T >"));
+        assert!(emitted.contains("& mut self . items"));
+
+        let pair: DeriveInput = syn::parse_str("struct Pair(u8, u8);")?;
+        let error = Wrapper::try_from(&pair).err().unwrap();
+        assert!(error.to_string() == "AsMut needs a struct of exactly one field, not 2");
+
+        let unit: DeriveInput = syn::parse_str("struct Unit;")?;
+        assert!(Wrapper::try_from(&unit).is_err());
+
+        Ok(())
+    }
+}
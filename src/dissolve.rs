@@ -5,97 +5,424 @@ use std::{
 };
 
 use proc_macro2::{TokenStream, Span};
-use quote::quote;
+use quote::{quote, quote_spanned, format_ident};
 use syn::{
     DeriveInput,
-    FieldsNamed,
+    Fields,
+    Member,
+    Index,
     Type,
     Ident,
+    LitStr,
+    LitInt,
+    Path,
+    PathArguments,
+    Visibility,
     Result,
     Error,
     TypeTuple,
     AttrStyle,
-    LitStr,
     Attribute,
+    Meta,
     token::Paren,
+    parse_quote,
     punctuated::Punctuated,
     parse::{Parse, ParseStream},
+    spanned::Spanned,
 };
 
 use crate::{
-    extract::{named_fields, named_struct},
+    extract::{any_deprecated, fresh_lifetime, named_struct, new_name, suggest},
     faultmsg::Problem,
 };
 
 pub struct Field {
     ty: Type,    
+    member: Member,
     name: Ident,
+    /// The whole field as declared, eg; `name: String`.
+    span: Span,
 }
 
 impl Field {
-    fn from_field(field: &syn::Field) -> Result<Self> {
-        let name: Ident =  field.ident
-            .clone()
-            .ok_or(Error::new(Span::call_site(), Problem::UnnamedField))?;
-        
-        Ok(Field {
+    fn from_field(index: usize, field: &syn::Field) -> Result<Option<Self>> {
+        // Most fields carry no attributes at all.
+        if field.attrs.is_empty() {
+            return Ok(Some(Field::from_parts(index, field)));
+        }
+
+        let mut skip = false;
+        for action in get_actions_from(field.attrs.as_slice())? {
+            match action {
+                Action::Skip => skip = true,
+                action => return Err(Error::new_spanned(
+                    field,
+                    Problem::MisplacedOption(action.option(), "the struct"),
+                )),
+            }
+        }
+
+        if skip {
+            return Ok(None);
+        }
+
+        Ok(Some(Field::from_parts(index, field)))
+    }
+
+    /// Positional fields are bound to `field_<index>`.
+    fn from_parts(index: usize, field: &syn::Field) -> Self {
+        let (member, name) = match &field.ident {
+            Some(name) => (Member::Named(name.clone()), name.clone()),
+            None => (
+                Member::Unnamed(Index::from(index)),
+                format_ident!("field_{}", index),
+            ),
+        };
+
+        Field {
             ty: field.ty.clone(),
-            name: name,
-        })
+            member,
+            name,
+            span: field.span(),
+        }
     }
     
-    fn from_fields_named(fields_named: &FieldsNamed) -> Result<Vec<Self>> {
-        fields_named.named
+    /// A skipped field to drop before the tuple is built, eg; `guard` or `1` for a tuple
+    /// struct.
+    fn dropped(member: &Member, fields: &Fields, kept: &[Field]) -> Result<Self> {
+        let describe = |member: &Member| match member {
+            Member::Named(ident) => ident.to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        };
+        let found = fields
             .iter()
-            .map(|field| Field::from_field(field))
-            .collect()
+            .enumerate()
+            .find(|(index, field)| match (member, &field.ident) {
+                (Member::Named(name), Some(ident)) => name == ident,
+                (Member::Unnamed(position), None) => position.index as usize == *index,
+                _ => false,
+            });
+        let (index, field) = found.ok_or_else(|| {
+            Error::new_spanned(member, Problem::DropFieldMissing(describe(member)))
+        })?;
+        let field = Field::from_parts(index, field);
+        if kept.iter().any(|kept| kept.member == field.member) {
+            return Err(Error::new_spanned(
+                member,
+                Problem::DropFieldDissolved(describe(member)),
+            ));
+        }
+
+        Ok(field)
+    }
+
+    /// Fields of a named or tuple struct, in order, leaving out the skipped ones.
+    fn from_fields(fields: &Fields) -> Result<(Vec<Self>, bool)> {
+        if let Fields::Unit = fields {
+            return Err(Error::new(Span::call_site(), Problem::UnnamedField));
+        }
+
+        let mut skipped = false;
+        let mut kept = Vec::new();
+        for (index, field) in fields.iter().enumerate() {
+            match Field::from_field(index, field)? {
+                Some(field) => kept.push(field),
+                None => skipped = true,
+            }
+        }
+
+        Ok((kept, skipped))
+    }
+
+    /// The field's element of the dissolved tuple, spanned on the field so errors about
+    /// it, such as a type that can't be moved out, point there.
+    fn element(&self) -> TokenStream {
+        let name = &self.name;
+        quote_spanned!(self.span=> #name)
+    }
+
+    /// Binds the field in a `Self { .. }` pattern, eg; `name` or `0: field_0`.
+    fn pattern(&self) -> TokenStream {
+        let name = &self.name;
+        match &self.member {
+            Member::Named(_) => quote!(#name),
+            Member::Unnamed(index) => quote!(#index: #name),
+        }
     }
 }
 
-struct Rename {
-    name: Ident,
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Action {
+    Rename(Ident),
+    Trace,
+    FromImpl,
+    With,
+    Reconstruct,
+    TryFrom,
+    Alias,
+    Skip,
+    IntoParts(Vec<Ident>),
+    ByRef,
+    ByMut,
+    ExplicitLifetime,
+    Vis(Box<Visibility>),
+    DropFirst(Vec<Member>),
+    AsOptions,
+    StableArity(LitInt),
+    IntoVariant(Box<Path>),
+    Tuple,
+    Struct,
+    GroupTail(LitInt),
+}
+
+impl Action {
+    /// Name of the attribute option.
+    fn option(&self) -> &'static str {
+        match self {
+            Action::Rename(_) => "rename",
+            Action::Trace => "trace",
+            Action::FromImpl => "from_impl",
+            Action::With => "with",
+            Action::Reconstruct => "reconstruct",
+            Action::TryFrom => "try_from",
+            Action::Alias => "alias",
+            Action::Skip => "skip",
+            Action::IntoParts(_) => "into_parts",
+            Action::ByRef => "by_ref",
+            Action::ByMut => "mut",
+            Action::ExplicitLifetime => "explicit_lifetime",
+            Action::Vis(_) => "vis",
+            Action::DropFirst(_) => "drop_first",
+            Action::AsOptions => "as_options",
+            Action::StableArity(_) => "stable_arity",
+            Action::IntoVariant(_) => "into_variant",
+            Action::Tuple => "tuple",
+            Action::Struct => "struct",
+            Action::GroupTail(_) => "group_tail",
+        }
+    }
+}
+
+/// Every option accepted within `#[dissolve(...)]`.
+const OPTIONS: &[&str] = &[
+    "rename", "trace", "from_impl", "with", "reconstruct", "try_from", "alias", "skip",
+    "into_parts", "by_ref", "mut", "explicit_lifetime", "vis", "drop_first", "as_options",
+    "stable_arity", "into_variant", "tuple", "struct",
+    "group_tail",
+];
+
+/// Whether an option has been fully parsed. Options are separated by commas.
+fn ended(input: ParseStream) -> bool {
+    input.is_empty() || input.peek(syn::Token![,])
+}
+
+/// Parse an option that takes no further tokens.
+fn flag(input: ParseStream, action: Action, name: &'static str) -> Result<Action> {
+    if !ended(input) {
+        Err(Error::new(Span::call_site(), Problem::TokensFollowFlag(name)))
+    } else {
+        Ok(action)
+    }
 }
 
-impl Parse for Rename {
+impl Parse for Action {
     fn parse(input: ParseStream) -> Result<Self> {
         syn::custom_keyword!(rename);
+        syn::custom_keyword!(trace);
+        syn::custom_keyword!(from_impl);
+        syn::custom_keyword!(with);
+        syn::custom_keyword!(reconstruct);
+        syn::custom_keyword!(try_from);
+        syn::custom_keyword!(alias);
+        syn::custom_keyword!(skip);
+        syn::custom_keyword!(into_parts);
+        syn::custom_keyword!(by_ref);
+        syn::custom_keyword!(explicit_lifetime);
+        syn::custom_keyword!(vis);
+        syn::custom_keyword!(drop_first);
+        syn::custom_keyword!(as_options);
+        syn::custom_keyword!(stable_arity);
+        syn::custom_keyword!(into_variant);
+        syn::custom_keyword!(tuple);
+        syn::custom_keyword!(group_tail);
 
         if input.peek(rename) {
             let _ = input.parse::<rename>()?;
             let _ = input.parse::<syn::Token![=]>()?;
-            let name = input.parse::<LitStr>()?;
-            if !input.is_empty() {
+            let name = new_name(input)?;
+            if !ended(input) {
                 Err(Error::new(Span::call_site(), Problem::TokensFollowNewName))
             } else {
-                let name = Ident::new(name.value().as_str(), Span::call_site());
-                Ok(Rename { name } )
+                Ok(Action::Rename(name))
             }
+        } else if input.peek(trace) {
+            let _ = input.parse::<trace>()?;
+            flag(input, Action::Trace, "trace")
+        } else if input.peek(from_impl) {
+            let _ = input.parse::<from_impl>()?;
+            flag(input, Action::FromImpl, "from_impl")
+        } else if input.peek(with) {
+            let _ = input.parse::<with>()?;
+            flag(input, Action::With, "with")
+        } else if input.peek(reconstruct) {
+            let _ = input.parse::<reconstruct>()?;
+            flag(input, Action::Reconstruct, "reconstruct")
+        } else if input.peek(try_from) {
+            let _ = input.parse::<try_from>()?;
+            flag(input, Action::TryFrom, "try_from")
+        } else if input.peek(alias) {
+            let _ = input.parse::<alias>()?;
+            flag(input, Action::Alias, "alias")
+        } else if input.peek(skip) {
+            let _ = input.parse::<skip>()?;
+            flag(input, Action::Skip, "skip")
+        } else if input.peek(into_parts) {
+            let _ = input.parse::<into_parts>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let names = Punctuated::<Ident, syn::Token![,]>::parse_terminated(&content)?;
+            flag(input, Action::IntoParts(names.into_iter().collect()), "into_parts")
+        } else if input.peek(by_ref) {
+            let _ = input.parse::<by_ref>()?;
+            flag(input, Action::ByRef, "by_ref")
+        } else if input.peek(syn::Token![mut]) {
+            let _ = input.parse::<syn::Token![mut]>()?;
+            flag(input, Action::ByMut, "mut")
+        } else if input.peek(explicit_lifetime) {
+            let _ = input.parse::<explicit_lifetime>()?;
+            flag(input, Action::ExplicitLifetime, "explicit_lifetime")
+        } else if input.peek(vis) {
+            let _ = input.parse::<vis>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let visibility = input.parse::<LitStr>()?.parse::<Visibility>()?;
+            flag(input, Action::Vis(Box::new(visibility)), "vis")
+        } else if input.peek(drop_first) {
+            let _ = input.parse::<drop_first>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let content;
+            syn::bracketed!(content in input);
+            let members = Punctuated::<Member, syn::Token![,]>::parse_terminated(&content)?;
+            flag(input, Action::DropFirst(members.into_iter().collect()), "drop_first")
+        } else if input.peek(as_options) {
+            let _ = input.parse::<as_options>()?;
+            flag(input, Action::AsOptions, "as_options")
+        } else if input.peek(stable_arity) {
+            let _ = input.parse::<stable_arity>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let arity = input.parse::<LitInt>()?;
+            let _ = arity.base10_parse::<usize>()?;
+            flag(input, Action::StableArity(arity), "stable_arity")
+        } else if input.peek(into_variant) {
+            let _ = input.parse::<into_variant>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let path = input.parse::<LitStr>()?.parse::<Path>()?;
+            flag(input, Action::IntoVariant(Box::new(path)), "into_variant")
+        } else if input.peek(tuple) {
+            let _ = input.parse::<tuple>()?;
+            flag(input, Action::Tuple, "tuple")
+        } else if input.peek(syn::Token![struct]) {
+            let _ = input.parse::<syn::Token![struct]>()?;
+            flag(input, Action::Struct, "struct")
+        } else if input.peek(group_tail) {
+            let _ = input.parse::<group_tail>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let count = content.parse::<LitInt>()?;
+            let _ = count.base10_parse::<usize>()?;
+            flag(input, Action::GroupTail(count), "group_tail")
+        } else if input.peek(Ident) {
+            let key = input.parse::<Ident>()?;
+            let name = key.to_string();
+            let suggestion = suggest(name.as_str(), OPTIONS);
+            Err(Error::new(key.span(), Problem::UnknownDissolveOption(name, suggestion)))
         } else {
             Err(Error::new(Span::call_site(), Problem::InvalidAttribute))
         }
     }
 }
 
-fn dissolve_rename_from(attributes: &[Attribute]) -> Result<Option<Ident>> {
-    let mut current: Option<Ident> = None;
+fn get_actions_from(attributes: &[Attribute]) -> Result<Vec<Action>> {
+    let mut actions: Vec<Action> = Vec::new();
 
     for attr in attributes {
         if attr.style != AttrStyle::Outer { continue; }
 
-        if attr.path().is_ident("dissolve") {
-            let rename = attr.parse_args::<Rename>()?;
-            current = Some(rename.name);
+        // A bare `#[dissolve]` asks for nothing beyond the default.
+        if attr.path().is_ident("dissolve") && !matches!(attr.meta, Meta::Path(_)) {
+            let options = attr.parse_args_with(
+                Punctuated::<Action, syn::Token![,]>::parse_terminated
+            )?;
+            actions.extend(options);
         }
     }
 
-    Ok(current)
+    Ok(actions)
 }
 
 pub struct NamedStruct<'a> {
     original: &'a DeriveInput,
     name: Ident,
     fields: Vec<Field>,
+    skipped: bool,
     dissolve_rename: Option<Ident>,
+    trace: bool,
+    from_impl: bool,
+    with: bool,
+    reconstruct: bool,
+    try_from: bool,
+    alias: bool,
+    parts: Option<Vec<Ident>>,
+    by_ref: bool,
+    by_mut: bool,
+    explicit_lifetime: bool,
+    vis: Visibility,
+    drop_first: Vec<Field>,
+    as_options: bool,
+    stable_arity: Option<LitInt>,
+    into_variant: Option<Variant>,
+    /// How many of the last fields are dissolved into a nested tuple.
+    group_tail: Option<usize>,
+    /// Any field is `#[deprecated]`, so the generated items allow its use.
+    deprecated: bool,
+}
+
+/// An enum variant to dissolve into rather than a tuple, eg; `State::Done`.
+struct Variant {
+    path: Path,
+    /// The enum, the path without the variant and any turbofish, eg; `State<T>`.
+    enum_ty: Type,
+    tuple: bool,
+}
+
+impl Variant {
+    fn new(path: Path, tuple: bool) -> Result<Self> {
+        if path.segments.len() < 2 {
+            let written = quote!(#path).to_string().replace(' ', "");
+            return Err(Error::new_spanned(&path, Problem::IntoVariantPath(written)));
+        }
+
+        let mut enum_path = path.clone();
+        enum_path.segments.pop();
+        enum_path.segments.pop_punct();
+        for segment in enum_path.segments.iter_mut() {
+            if let PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+                arguments.colon2_token = None;
+            }
+        }
+
+        Ok(Variant { path, enum_ty: parse_quote!(#enum_path), tuple })
+    }
+
+    /// Builds the variant from the fields bound by name.
+    fn emit(&self, fields: &[Field]) -> TokenStream {
+        let path = &self.path;
+        let names = fields.iter().map(|field| &field.name);
+        if self.tuple {
+            quote!(#path(#(#names),*))
+        } else {
+            quote!(#path { #(#names),* })
+        }
+    }
 }
 
 impl<'a> NamedStruct<'a> {
@@ -103,6 +430,7 @@ impl<'a> NamedStruct<'a> {
         let (impl_generics, struct_generics, where_clause) = self.original.generics
             .split_for_impl();        
         let struct_name = &self.name;
+        let vis = &self.vis;
 
         let types: Punctuated<Type, syn::Token![,]> = self.fields
             .iter()
@@ -111,11 +439,23 @@ impl<'a> NamedStruct<'a> {
                 p
             });
 
-        let type_tuple = TypeTuple {
+        let plain_tuple = TypeTuple {
             paren_token: Default::default(),
             elems: types,
         };
 
+        // Only the dissolved tuple is wrapped, so `reconstruct` and `try_from` still take
+        // the plain fields back.
+        let type_tuple = if self.as_options {
+            let elems = plain_tuple.elems
+                .iter()
+                .map(|ty| -> Type { parse_quote!(::core::option::Option<#ty>) })
+                .collect();
+            TypeTuple { paren_token: Default::default(), elems }
+        } else {
+            plain_tuple.clone()
+        };
+
         let fields: TokenStream = self.fields
             .iter()
             .enumerate()
@@ -124,33 +464,284 @@ impl<'a> NamedStruct<'a> {
                     ts.extend(quote!(,))
                 }
                 
-                let field_name = &field.name;
-                let field_expr = quote!(
-                    self.#field_name
-                );
-
-                ts.extend(field_expr);
+                ts.extend(field.element());
 
                 ts
             });
+        // Without the trailing comma `(field)` is just the field, not a one-tuple. As the
+        // `TypeTuple` already adds one for a single element, so must the expressions.
+        let single = if self.fields.len() == 1 { quote!(,) } else { TokenStream::new() };
+        let fields = quote!(#fields #single);
+
+        let elements: Vec<TokenStream> = self.fields
+            .iter()
+            .map(|field| {
+                let element = field.element();
+                if self.as_options {
+                    quote_spanned!(field.span=> ::core::option::Option::Some(#element))
+                } else {
+                    element
+                }
+            })
+            .collect();
+        let dissolved = quote!(#(#elements),* #single);
+
+        // The last fields are nested, eg; `(a, b, (c, d))`. The outer trailing comma keeps
+        // it a tuple when every field is grouped.
+        let (type_tuple, dissolved) = match self.group_tail {
+            Some(tail) => {
+                let split = type_tuple.elems.len() - tail;
+                let types: Vec<&Type> = type_tuple.elems.iter().collect();
+                let (head_types, tail_types) = types.split_at(split);
+                let (head_exprs, tail_exprs) = elements.split_at(split);
+                let nested: TypeTuple = parse_quote!((#(#tail_types,)*));
+                let mut elems: Punctuated<Type, syn::Token![,]> = head_types
+                    .iter()
+                    .map(|ty| (*ty).clone())
+                    .collect();
+                elems.push(Type::Tuple(nested));
+                (
+                    TypeTuple { paren_token: Default::default(), elems },
+                    quote!(#(#head_exprs,)* (#(#tail_exprs,)*),),
+                )
+            },
+            None => (type_tuple, dissolved),
+        };
+
+        // Token streams can't carry comments, so each field is bound by name instead.
+        // This keeps expanded code self-documenting as to which element is which.
+        let patterns: Vec<TokenStream> = self.fields
+            .iter()
+            .map(|field| field.pattern())
+            .collect();
+        let rest = if self.skipped { quote!(..) } else { TokenStream::new() };
+        let destructure = quote!({ #(#patterns,)* #rest });
+
+        // Fields to drop first are bound too, so they are dropped before the tuple is built
+        // rather than with the rest of `self` once the method returns.
+        let dropped_patterns = self.drop_first.iter().map(|field| field.pattern());
+        let dropped = self.drop_first.iter().map(|field| &field.name);
+        let consuming = quote!({ #(#patterns,)* #(#dropped_patterns,)* #rest });
+        let drops = quote!(#(::core::mem::drop(#dropped);)*);
+        let consume = quote!(
+            let Self #consuming = self;
+            #drops
+        );
 
         let dissolve = Ident::new("dissolve", Span::call_site());
         let fn_name = self.dissolve_rename
             .as_ref()
             .unwrap_or(&dissolve);
+
+        let (returns, built) = match &self.into_variant {
+            Some(variant) => {
+                let enum_ty = &variant.enum_ty;
+                (quote!(#enum_ty), variant.emit(self.fields.as_slice()))
+            },
+            None => (quote!(#type_tuple), quote!(( #dissolved ))),
+        };
+
+        let trace = self.emit_trace();
+        // Destructuring or building a deprecated field would warn in the user's crate.
+        let allow = if self.deprecated {
+            quote!(#[allow(deprecated)])
+        } else {
+            TokenStream::new()
+        };
+
+        let from_impl = if self.from_impl {
+            quote!(
+                #allow
+                impl #impl_generics From<#struct_name #struct_generics> for #returns
+                    #where_clause
+                {
+                    fn from(value: #struct_name #struct_generics) -> Self {
+                        let #struct_name #consuming = value;
+                        #drops
+                        #built
+                    }
+                }
+            )
+        } else {
+            TokenStream::new()
+        };
+
+        // `TryFrom` comes from the standard blanket impl over `From`, with an `Infallible`
+        // error, until there is a hook to validate the tuple.
+        let try_from = if self.try_from {
+            let bindings = self.fields.iter().map(|field| &field.name);
+            quote!(
+                #allow
+                impl #impl_generics From<#plain_tuple> for #struct_name #struct_generics
+                    #where_clause
+                {
+                    fn from(tuple: #plain_tuple) -> Self {
+                        let (#(#bindings,)*) = tuple;
+                        Self { #(#patterns,)* }
+                    }
+                }
+            )
+        } else {
+            TokenStream::new()
+        };
+
+        let with = if self.with {
+            let with_name = format_ident!("{}_with", fn_name);
+            let types = self.fields.iter().map(|field| &field.ty);
+            // The closure mustn't be shadowed by a field binding of the same name. Generic
+            // parameters aren't hygienic, so the return type's is named to avoid the
+            // struct's own.
+            let f = Ident::new("f", Span::mixed_site());
+            quote!(
+                #vis fn #with_name<__DissolveR>(
+                    self,
+                    #f: impl FnOnce(#(#types),*) -> __DissolveR,
+                ) -> __DissolveR {
+                    #trace
+                    #consume
+                    #f(
+                        #fields
+                    )
+                }
+            )
+        } else {
+            TokenStream::new()
+        };
+
+        // The alias only forwards, so `self` is consumed once by the primary method.
+        let alias = match &self.dissolve_rename {
+            Some(rename) if self.alias => quote!(
+                #vis fn dissolve(self) -> #returns {
+                    self.#rename()
+                }
+            ),
+            _ => TokenStream::new(),
+        };
+
+        let into_parts = match &self.parts {
+            Some(taken) => {
+                let taking: Vec<&Field> = taken
+                    .iter()
+                    .filter_map(|name| self.fields.iter().find(|field| field.name == *name))
+                    .collect();
+                let rest: Vec<&Field> = self.fields
+                    .iter()
+                    .filter(|field| !taken.contains(&field.name))
+                    .collect();
+                let taken_types = taking.iter().map(|field| &field.ty);
+                let taken_names = taking.iter().map(|field| &field.name);
+                let rest_types = rest.iter().map(|field| &field.ty);
+                let rest_names = rest.iter().map(|field| &field.name);
+                quote!(
+                    #vis fn into_parts(self) -> ((#(#taken_types,)*), (#(#rest_types,)*)) {
+                        #trace
+                        #consume
+                        ((#(#taken_names,)*), (#(#rest_names,)*))
+                    }
+                )
+            },
+            None => TokenStream::new(),
+        };
+
+        // The fields are disjoint, so the borrow of `self` splits into one per field.
+        let borrowed = |suffix: &str, mutability: Option<syn::Token![mut]>| {
+            let borrow_name = format_ident!("{}_{}", fn_name, suffix);
+            // Named so as not to shadow a lifetime of the struct's.
+            let lifetime = if self.explicit_lifetime {
+                Some(fresh_lifetime("dissolve", &self.original.generics))
+            } else {
+                None
+            };
+            let generic = lifetime.as_ref().map(|lifetime| quote!(<#lifetime>));
+            let types = self.fields.iter().map(|field| &field.ty);
+            quote!(
+                #vis fn #borrow_name #generic (&#lifetime #mutability self)
+                    -> (#(&#lifetime #mutability #types,)*)
+                {
+                    let Self #destructure = self;
+                    (
+                        #fields
+                    )
+                }
+            )
+        };
+
+        let by_ref = if self.by_ref {
+            borrowed("ref", None)
+        } else {
+            TokenStream::new()
+        };
+
+        let by_mut = if self.by_mut {
+            borrowed("mut", Some(Default::default()))
+        } else {
+            TokenStream::new()
+        };
+
+        let reconstruct = if self.reconstruct {
+            let bindings = self.fields.iter().map(|field| &field.name);
+            quote!(
+                #vis fn reconstruct(tuple: #plain_tuple) -> Self {
+                    let (#(#bindings,)*) = tuple;
+                    Self { #(#patterns,)* }
+                }
+            )
+        } else {
+            TokenStream::new()
+        };
         
+        // Spanned on the attribute's arity, so a mismatch is reported there.
+        let stable_arity = self.stable_arity.as_ref().map(|arity| {
+            let count = type_tuple.elems.len();
+            quote_spanned!(arity.span()=>
+                const _: [(); #arity] = [(); #count];
+            )
+        });
+
         quote!(
+            #allow
             impl #impl_generics #struct_name #struct_generics
                 #where_clause
             {
-                pub fn #fn_name(self) -> #type_tuple {
-                    (
-                        #fields
-                    )
+                #vis fn #fn_name(self) -> #returns {
+                    #trace
+                    #consume
+                    #built
                 }
+
+                #alias
+
+                #with
+
+                #into_parts
+
+                #by_ref
+
+                #by_mut
+
+                #reconstruct
             }
+
+            #from_impl
+
+            #try_from
+
+            #stable_arity
         )        
     }
+
+    /// The trace call is only emitted when the `tracing` feature is enabled. The path is
+    /// absolute so a `tracing` module of the user's can't stand in for the crate.
+    fn emit_trace(&self) -> TokenStream {
+        let struct_name = &self.name;
+        if cfg!(feature = "tracing") && self.trace {
+            quote!(
+                ::tracing::trace!("dissolving {}", stringify!(#struct_name));
+            )
+        } else {
+            TokenStream::new()
+        }
+    }
 }
 
 impl<'a> TryFrom<&'a DeriveInput> for NamedStruct<'a> {
@@ -158,15 +749,610 @@ impl<'a> TryFrom<&'a DeriveInput> for NamedStruct<'a> {
     
     fn try_from(node: &'a DeriveInput) -> Result<Self> {
         let struct_data = named_struct(node)?;
-        let named_fields = named_fields(struct_data)?;
-        let fields = Field::from_fields_named(named_fields)?;
-        let rename = dissolve_rename_from(node.attrs.as_slice())?;
+        let (fields, skipped) = Field::from_fields(&struct_data.fields)?;
+        let mut rename = None;
+        let mut trace = false;
+        let mut from_impl = false;
+        let mut with = false;
+        let mut reconstruct = false;
+        let mut try_from = false;
+        let mut alias = false;
+        let mut parts = None;
+        let mut by_ref = false;
+        let mut by_mut = false;
+        let mut explicit_lifetime = false;
+        let mut vis: Visibility = parse_quote!(pub);
+        let mut drop_first = Vec::new();
+        let mut as_options = false;
+        let mut stable_arity = None;
+        let mut variant = None;
+        let mut shape = None;
+        let mut group_tail = None;
+
+        for action in get_actions_from(node.attrs.as_slice())? {
+            match action {
+                Action::Rename(name) => rename = Some(name),
+                Action::Trace => trace = true,
+                Action::FromImpl => from_impl = true,
+                Action::With => with = true,
+                Action::Reconstruct => reconstruct = true,
+                Action::TryFrom => try_from = true,
+                Action::Alias => alias = true,
+                Action::IntoParts(taken) => {
+                    let missing = taken
+                        .iter()
+                        .find(|name| !fields.iter().any(|field| field.name == **name));
+                    if let Some(name) = missing {
+                        return Err(Error::new(
+                            name.span(),
+                            Problem::PartFieldMissing(name.to_string()),
+                        ));
+                    }
+                    // Repeated `into_parts` add to the parts taken. A field taken twice
+                    // is reported at its second mention.
+                    let mut taking: Vec<Ident> = parts.take().unwrap_or_default();
+                    for name in taken {
+                        if taking.contains(&name) {
+                            return Err(Error::new(
+                                name.span(),
+                                Problem::DuplicatePart(name.to_string()),
+                            ));
+                        }
+                        taking.push(name);
+                    }
+                    parts = Some(taking);
+                },
+                Action::ByRef => by_ref = true,
+                Action::ByMut => by_mut = true,
+                Action::ExplicitLifetime => explicit_lifetime = true,
+                Action::Vis(visibility) => vis = *visibility,
+                Action::DropFirst(members) => {
+                    for member in members {
+                        drop_first.push(Field::dropped(&member, &struct_data.fields, &fields)?);
+                    }
+                },
+                Action::AsOptions => as_options = true,
+                Action::StableArity(arity) => stable_arity = Some(arity),
+                Action::IntoVariant(path) => variant = Some(*path),
+                Action::Tuple => shape = Some(Action::Tuple),
+                Action::Struct => shape = Some(Action::Struct),
+                Action::GroupTail(count) => {
+                    let tail = count.base10_parse::<usize>()?;
+                    if tail == 0 || tail > fields.len() {
+                        return Err(Error::new(
+                            count.span(),
+                            Problem::GroupTailCount(tail, fields.len()),
+                        ));
+                    }
+                    group_tail = Some(tail);
+                },
+                Action::Skip => return Err(Error::new(
+                    Span::call_site(),
+                    Problem::MisplacedOption("skip", "a field"),
+                )),
+            }
+        }
+
+        if skipped && (reconstruct || try_from) {
+            let option = if reconstruct { "reconstruct" } else { "try_from" };
+            return Err(Error::new(Span::call_site(), Problem::ReconstructSkipped(option)));
+        }
+
+        // Without a shape, the variant is taken to be shaped as the struct.
+        let tuple_struct = matches!(struct_data.fields, Fields::Unnamed(_));
+        let into_variant = match (variant, shape) {
+            (Some(_), _) if as_options => return Err(Error::new(
+                Span::call_site(),
+                Problem::IntoVariantConflict("as_options"),
+            )),
+            (Some(_), _) if group_tail.is_some() => return Err(Error::new(
+                Span::call_site(),
+                Problem::IntoVariantConflict("group_tail"),
+            )),
+            (Some(_), Some(Action::Struct)) if tuple_struct => return Err(Error::new(
+                Span::call_site(),
+                Problem::MisplacedOption("struct", "a struct with named fields"),
+            )),
+            (Some(path), shape) => {
+                let tuple = shape.map(|shape| shape == Action::Tuple).unwrap_or(tuple_struct);
+                Some(Variant::new(path, tuple)?)
+            },
+            (None, Some(shape)) => return Err(Error::new(
+                Span::call_site(),
+                Problem::VariantShapeMisplaced(shape.option()),
+            )),
+            (None, None) => None,
+        };
+
+        // Only a borrowing dissolve has a borrow to name.
+        if explicit_lifetime && !(by_ref || by_mut) {
+            return Err(Error::new(Span::call_site(), Problem::LifetimeWithoutBorrow));
+        }
+
+        // A getter for such a field would share its name with the method.
+        let dissolve = Ident::new("dissolve", Span::call_site());
+        let method = rename.as_ref().unwrap_or(&dissolve);
+
+        // Without a new name there's nothing for `dissolve` to forward to.
+        if alias && *method == dissolve {
+            let attribute = node.attrs
+                .iter()
+                .find(|attr| {
+                    get_actions_from(std::slice::from_ref(*attr))
+                        .map(|actions| actions.contains(&Action::Alias))
+                        .unwrap_or(false)
+                });
+            return Err(match attribute {
+                Some(attribute) => Error::new_spanned(attribute, Problem::AliasWithoutRename),
+                None => Error::new(Span::call_site(), Problem::AliasWithoutRename),
+            });
+        }
+        let colliding = struct_data.fields
+            .iter()
+            .filter_map(|field| field.ident.as_ref())
+            .find(|name| *name == method || (alias && *name == "dissolve"));
+        if let Some(name) = colliding {
+            return Err(Error::new(
+                name.span(),
+                Problem::DissolveNameCollidesWithField(name.to_string()),
+            ));
+        }
 
         Ok(NamedStruct {
             original: node,
             name: node.ident.clone(),
             fields,
+            skipped,
             dissolve_rename: rename,
+            trace,
+            from_impl,
+            with,
+            reconstruct,
+            try_from,
+            alias,
+            parts,
+            by_ref,
+            by_mut,
+            explicit_lifetime,
+            vis,
+            drop_first,
+            as_options,
+            stable_arity,
+            into_variant,
+            group_tail,
+            deprecated: any_deprecated(&struct_data.fields),
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_action() -> Result<()> {
+        let a: Action = syn::parse_str("rename = \"shatter\"")?;
+        let check = Action::Rename(Ident::new("shatter", Span::call_site()));
+        assert!(a == check);
+
+        let a: Action = syn::parse_str("rename = shatter")?;
+        assert!(a == check);
+
+        let a: Action = syn::parse_str("trace")?;
+        assert!(a == Action::Trace);
+
+        let r: Result<Action> = syn::parse_str("trace = \"loud\"");
+        assert!(r.is_err());
+
+        let a: Action = syn::parse_str("from_impl")?;
+        assert!(a == Action::FromImpl);
+
+        let a: Action = syn::parse_str("with")?;
+        assert!(a == Action::With);
+
+        let a: Action = syn::parse_str("reconstruct")?;
+        assert!(a == Action::Reconstruct);
+
+        let a: Action = syn::parse_str("alias")?;
+        assert!(a == Action::Alias);
+
+        let a: Action = syn::parse_str("skip")?;
+        assert!(a == Action::Skip);
+
+        let a: Action = syn::parse_str("into_parts(a, b)")?;
+        let check = Action::IntoParts(vec![
+            Ident::new("a", Span::call_site()),
+            Ident::new("b", Span::call_site()),
+        ]);
+        assert!(a == check);
+
+        let a: Action = syn::parse_str("by_ref")?;
+        assert!(a == Action::ByRef);
+
+        let a: Action = syn::parse_str("mut")?;
+        assert!(a == Action::ByMut);
+
+        let a: Action = syn::parse_str("explicit_lifetime")?;
+        assert!(a == Action::ExplicitLifetime);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_options() -> Result<()> {
+        let node: DeriveInput = syn::parse_str(
+            "#[dissolve(by_ref, explicit_lifetime)] #[dissolve(rename = shatter, alias)] struct S;"
+        )?;
+        let actions = get_actions_from(node.attrs.as_slice())?;
+        let check = vec![
+            Action::ByRef,
+            Action::ExplicitLifetime,
+            Action::Rename(Ident::new("shatter", Span::call_site())),
+            Action::Alias,
+        ];
+        assert!(actions == check);
+
+        let node: DeriveInput = syn::parse_str("#[dissolve(with reconstruct)] struct S;")?;
+        assert!(get_actions_from(node.attrs.as_slice()).is_err());
+
+        let node: DeriveInput = syn::parse_str("#[dissolve] struct S;")?;
+        assert!(get_actions_from(node.attrs.as_slice())?.is_empty());
+
+        let node: DeriveInput = syn::parse_str("#[dissolve(aliias)] struct S;")?;
+        let error = get_actions_from(node.attrs.as_slice()).unwrap_err();
+        assert!(error.to_string() == "unknown dissolve option `aliias`, did you mean `alias`?");
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_named_bindings() -> Result<()> {
+        let stuff: DeriveInput = syn::parse_str(
+            "struct Stuff { name: String, price: f64, count: usize }"
+        )?;
+        let emitted = NamedStruct::try_from(&stuff)?.emit().to_string();
+        assert!(emitted.contains("let Self { name , price , count , } = self ;"));
+        assert!(emitted.contains("(name , price , count)"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_single_field() -> Result<()> {
+        let single: DeriveInput = syn::parse_str("struct Single { a: u64 }")?;
+        let emitted = NamedStruct::try_from(&single)?.emit().to_string();
+        assert!(emitted.contains("pub fn dissolve (self) -> (u64 ,)"));
+        assert!(emitted.contains("let Self { a , } = self ; (a ,)"));
+
+        let options: DeriveInput = syn::parse_str(
+            "#[dissolve(as_options)] struct Single(u8);"
+        )?;
+        let emitted = NamedStruct::try_from(&options)?.emit().to_string();
+        assert!(emitted.contains("(:: core :: option :: Option :: Some (field_0) ,)"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn ignores_foreign_attributes() -> Result<()> {
+        let plain: DeriveInput = syn::parse_str("struct Plain(u64);")?;
+        let plain = NamedStruct::try_from(&plain)?.emit().to_string();
+
+        let transparent: DeriveInput = syn::parse_str(
+            "#[repr(transparent)] struct Plain(#[doc = \"a\"] u64);"
+        )?;
+        assert!(NamedStruct::try_from(&transparent)?.emit().to_string() == plain);
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_tuple_skip() -> Result<()> {
+        let triple: DeriveInput = syn::parse_str(
+            "struct Triple(u8, #[dissolve(skip)] u16, u32);"
+        )?;
+        let emitted = NamedStruct::try_from(&triple)?.emit().to_string();
+        assert!(emitted.contains("let Self { 0 : field_0 , 2 : field_2 , .. } = self ;"));
+        assert!(emitted.contains("-> (u8 , u32)"));
+
+        let reconstructed: DeriveInput = syn::parse_str(
+            "#[dissolve(reconstruct)] struct Triple(u8, #[dissolve(skip)] u16, u32);"
+        )?;
+        assert!(NamedStruct::try_from(&reconstructed).is_err());
+
+        let misplaced: DeriveInput = syn::parse_str(
+            "#[dissolve(skip)] struct Triple(u8, u16, u32);"
+        )?;
+        assert!(NamedStruct::try_from(&misplaced).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn name_collides_with_field() -> Result<()> {
+        let colliding: DeriveInput = syn::parse_str(
+            "struct Colliding { dissolve: u8, b: u16 }"
+        )?;
+        assert!(NamedStruct::try_from(&colliding).is_err());
+
+        let renamed: DeriveInput = syn::parse_str(
+            "#[dissolve(rename = \"b\")] struct Renamed { a: u8, b: u16 }"
+        )?;
+        assert!(NamedStruct::try_from(&renamed).is_err());
+
+        let aliased: DeriveInput = syn::parse_str(
+            "#[dissolve(rename = \"shatter\", alias)] struct Aliased { dissolve: u8, b: u16 }"
+        )?;
+        assert!(NamedStruct::try_from(&aliased).is_err());
+
+        let avoided: DeriveInput = syn::parse_str(
+            "#[dissolve(rename = \"shatter\")] struct Avoided { dissolve: u8, b: u16 }"
+        )?;
+        assert!(NamedStruct::try_from(&avoided).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_vis() -> Result<()> {
+        let a: Action = syn::parse_str("vis = \"pub(crate)\"")?;
+        assert!(a == Action::Vis(Box::new(syn::parse_str("pub(crate)")?)));
+
+        let r: Result<Action> = syn::parse_str("vis = \"public\"");
+        assert!(r.is_err());
+
+        let scoped: DeriveInput = syn::parse_str(
+            "#[dissolve(vis = \"pub(crate)\", reconstruct)] struct Scoped { a: u8, b: u16 }"
+        )?;
+        let emitted = NamedStruct::try_from(&scoped)?.emit().to_string();
+        assert!(emitted.contains("pub (crate) fn dissolve (self)"));
+        assert!(emitted.contains("pub (crate) fn reconstruct (tuple"));
+
+        let private: DeriveInput = syn::parse_str(
+            "#[dissolve(vis = \"\")] struct Private { a: u8, b: u16 }"
+        )?;
+        let emitted = NamedStruct::try_from(&private)?.emit().to_string();
+        assert!(emitted.contains("{ fn dissolve (self)"));
+        assert!(!emitted.contains("pub"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_lifetime_bounds() -> Result<()> {
+        let bounded: DeriveInput = syn::parse_str(
+            "#[dissolve(from_impl)] struct S<'a, 'b: 'a> { x: &'a str, y: &'b str }"
+        )?;
+        let emitted = NamedStruct::try_from(&bounded)?.emit().to_string();
+        assert!(emitted.starts_with("impl < 'a , 'b : 'a > S < 'a , 'b > {"));
+        assert!(emitted.contains("impl < 'a , 'b : 'a > From < S < 'a , 'b > >"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_drop_first() -> Result<()> {
+        let a: Action = syn::parse_str("drop_first = [guard, 1]")?;
+        let check = Action::DropFirst(vec![syn::parse_str("guard")?, syn::parse_str("1")?]);
+        assert!(a == check);
+
+        let guarded: DeriveInput = syn::parse_str(
+            "#[dissolve(drop_first = [guard])] struct G { a: u8, #[dissolve(skip)] guard: L, b: u16 }"
+        )?;
+        let emitted = NamedStruct::try_from(&guarded)?.emit().to_string();
+        assert!(emitted.contains(
+            "let Self { a , b , guard , .. } = self ; :: core :: mem :: drop (guard) ;"
+        ));
+
+        let tuple: DeriveInput = syn::parse_str(
+            "#[dissolve(drop_first = [1])] struct T(u8, #[dissolve(skip)] L, u16);"
+        )?;
+        let emitted = NamedStruct::try_from(&tuple)?.emit().to_string();
+        assert!(emitted.contains(":: core :: mem :: drop (field_1) ;"));
+
+        let kept: DeriveInput = syn::parse_str(
+            "#[dissolve(drop_first = [a])] struct K { a: u8, #[dissolve(skip)] guard: L }"
+        )?;
+        assert!(NamedStruct::try_from(&kept).is_err());
+
+        let missing: DeriveInput = syn::parse_str(
+            "#[dissolve(drop_first = [c])] struct M { a: u8, #[dissolve(skip)] guard: L }"
+        )?;
+        assert!(NamedStruct::try_from(&missing).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_try_from() -> Result<()> {
+        let pair: DeriveInput = syn::parse_str(
+            "#[dissolve(try_from)] struct Pair<T> { a: T, b: u16 }"
+        )?;
+        let emitted = NamedStruct::try_from(&pair)?.emit().to_string();
+        assert!(emitted.contains("impl < T > From < (T , u16) > for Pair < T >"));
+        assert!(emitted.contains("let (a , b ,) = tuple ; Self { a , b , }"));
+
+        let skipped: DeriveInput = syn::parse_str(
+            "#[dissolve(try_from)] struct Pair { a: u8, #[dissolve(skip)] b: u16 }"
+        )?;
+        assert!(NamedStruct::try_from(&skipped).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_into_parts() -> Result<()> {
+        let parts: DeriveInput = syn::parse_str(
+            "#[dissolve(into_parts(c, a))] struct Parts { a: u8, b: u16, c: u32, d: u64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&parts)?.emit().to_string();
+        assert!(emitted.contains("into_parts (self) -> ((u32 , u8 ,) , (u16 , u64 ,))"));
+
+        let missing: DeriveInput = syn::parse_str(
+            "#[dissolve(into_parts(e))] struct Parts { a: u8, b: u16 }"
+        )?;
+        assert!(NamedStruct::try_from(&missing).is_err());
+
+        let repeated: DeriveInput = syn::parse_str(
+            "#[dissolve(into_parts(a))] #[dissolve(into_parts(b))] struct Parts { a: u8, b: u16, c: u32 }"
+        )?;
+        let emitted = NamedStruct::try_from(&repeated)?.emit().to_string();
+        assert!(emitted.contains("into_parts (self) -> ((u8 , u16 ,) , (u32 ,))"));
+
+        let duplicate: DeriveInput = syn::parse_str(
+            "#[dissolve(into_parts(a, b))] #[dissolve(into_parts(a))] struct Parts { a: u8, b: u16 }"
+        )?;
+        let error = NamedStruct::try_from(&duplicate).map(|_| ()).unwrap_err();
+        assert!(error.to_string() == Problem::DuplicatePart("a".to_owned()).to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_stable_arity() -> Result<()> {
+        let a: Action = syn::parse_str("stable_arity = 2")?;
+        assert!(a == Action::StableArity(syn::parse_str("2")?));
+
+        let r: Result<Action> = syn::parse_str("stable_arity = \"2\"");
+        assert!(r.is_err());
+
+        let r: Result<Action> = syn::parse_str("stable_arity = -1");
+        assert!(r.is_err());
+
+        let pinned: DeriveInput = syn::parse_str(
+            "#[dissolve(stable_arity = 2)] struct P { a: u8, #[dissolve(skip)] b: u16, c: u32 }"
+        )?;
+        let emitted = NamedStruct::try_from(&pinned)?.emit().to_string();
+        assert!(emitted.ends_with("const _ : [() ; 2] = [() ; 2usize] ;"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_into_variant() -> Result<()> {
+        let a: Action = syn::parse_str("into_variant = \"State::Done\"")?;
+        assert!(a == Action::IntoVariant(Box::new(syn::parse_str("State::Done")?)));
+
+        let a: Action = syn::parse_str("struct")?;
+        assert!(a == Action::Struct);
+
+        let named: DeriveInput = syn::parse_str(
+            "#[dissolve(into_variant = \"State::Done\")] struct Done { a: u8, b: u16 }"
+        )?;
+        let emitted = NamedStruct::try_from(&named)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub fn dissolve (self) -> State { let Self { a , b , } = self ; State :: Done { a , b } }"
+        ));
+
+        let tupled: DeriveInput = syn::parse_str(
+            "#[dissolve(into_variant = \"State::<T>::Done\", tuple)] struct Done<T> { a: T }"
+        )?;
+        let emitted = NamedStruct::try_from(&tupled)?.emit().to_string();
+        assert!(emitted.contains("-> State < T > {"));
+        assert!(emitted.contains("State :: < T > :: Done (a) }"));
+
+        let bare: DeriveInput = syn::parse_str(
+            "#[dissolve(into_variant = \"Done\")] struct Done { a: u8 }"
+        )?;
+        let error = NamedStruct::try_from(&bare).err().unwrap();
+        assert!(error.to_string().ends_with("not `Done`"));
+
+        let unnamed: DeriveInput = syn::parse_str(
+            "#[dissolve(into_variant = \"State::Done\", struct)] struct Done(u8);"
+        )?;
+        assert!(NamedStruct::try_from(&unnamed).is_err());
+
+        let shapeless: DeriveInput = syn::parse_str("#[dissolve(tuple)] struct Done { a: u8 }")?;
+        assert!(NamedStruct::try_from(&shapeless).is_err());
+
+        let unborrowed: DeriveInput = syn::parse_str(
+            "#[dissolve(explicit_lifetime)] struct Done { a: u8 }"
+        )?;
+        let error = NamedStruct::try_from(&unborrowed).err().unwrap();
+        assert!(error.to_string() == Problem::LifetimeWithoutBorrow.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_group_tail() -> Result<()> {
+        let a: Action = syn::parse_str("group_tail(2)")?;
+        assert!(a == Action::GroupTail(syn::parse_str("2")?));
+
+        let grouped: DeriveInput = syn::parse_str(
+            "#[dissolve(group_tail(2))] struct S { a: u8, b: u16, c: u32, d: u64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&grouped)?.emit().to_string();
+        assert!(emitted.contains("-> (u8 , u16 , (u32 , u64 ,)) {"));
+        assert!(emitted.contains("(a , b , (c , d ,) ,)"));
+
+        let every: DeriveInput = syn::parse_str(
+            "#[dissolve(group_tail(2), as_options)] struct S { a: u8, b: u16 }"
+        )?;
+        let emitted = NamedStruct::try_from(&every)?.emit().to_string();
+        assert!(emitted.contains(
+            "-> ((:: core :: option :: Option < u8 > , :: core :: option :: Option < u16 > ,) ,)"
+        ));
+
+        let over: DeriveInput = syn::parse_str(
+            "#[dissolve(group_tail(3))] struct S { a: u8, b: u16 }"
+        )?;
+        let error = NamedStruct::try_from(&over).err().unwrap();
+        assert!(error.to_string() == "group_tail(3) needs between 1 and 2 dissolved fields");
+
+        let none: DeriveInput = syn::parse_str("#[dissolve(group_tail(0))] struct S { a: u8 }")?;
+        assert!(NamedStruct::try_from(&none).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_callables() -> Result<()> {
+        let callbacks: DeriveInput = syn::parse_str(
+            "struct C<F: Fn()> { f: Box<dyn Fn() + Send>, p: fn(u32) -> u32, g: F }"
+        )?;
+        let emitted = NamedStruct::try_from(&callbacks)?.emit().to_string();
+        assert!(emitted.contains(
+            "-> (Box < dyn Fn () + Send > , fn (u32) -> u32 , F)"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_as_options() -> Result<()> {
+        let a: Action = syn::parse_str("as_options")?;
+        assert!(a == Action::AsOptions);
+
+        let staged: DeriveInput = syn::parse_str(
+            "#[dissolve(as_options, reconstruct)] struct Staged(u8, u16);"
+        )?;
+        let emitted = NamedStruct::try_from(&staged)?.emit().to_string();
+        assert!(emitted.contains(
+            "-> (:: core :: option :: Option < u8 > , :: core :: option :: Option < u16 >)"
+        ));
+        assert!(emitted.contains(
+            "(:: core :: option :: Option :: Some (field_0) , \
+             :: core :: option :: Option :: Some (field_1))"
+        ));
+        assert!(emitted.contains("fn reconstruct (tuple : (u8 , u16)) -> Self"));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn emit_trace() -> Result<()> {
+        let traced: DeriveInput = syn::parse_str(
+            "#[dissolve(trace)] struct Traced { a: u64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&traced)?.emit().to_string();
+        assert!(emitted.contains(":: tracing :: trace ! (\"dissolving {}\" , stringify ! (Traced))"));
+
+        let quiet: DeriveInput = syn::parse_str("struct Quiet { a: u64 }")?;
+        let emitted = NamedStruct::try_from(&quiet)?.emit().to_string();
+        assert!(!emitted.contains("tracing"));
+
+        Ok(())
+    }
+}
@@ -1,19 +1,43 @@
 //! Common functions
+//!
+//! These stay crate private. A `proc-macro` crate can only export its derives, so the
+//! helpers can't be made `pub` for companion crates to reuse. Their contracts are
+//! documented here for anyone copying them instead.
 
 use proc_macro2::Span;
-use syn::{FieldsNamed, DataStruct, DeriveInput, Data, Fields, Error, Result};
+use syn::{
+    FieldsNamed,
+    DataStruct,
+    DeriveInput,
+    Data,
+    Fields,
+    Generics,
+    Error,
+    Result,
+    Ident,
+    Lifetime,
+    LitStr,
+    parse::ParseStream,
+};
 
 use crate::faultmsg::{StructIs, Problem};
 
-pub fn named_fields<'a>(structure: &'a DataStruct) -> Result<&'a FieldsNamed> {
-    match structure.fields {
-        Fields::Named(ref fields) => Ok(fields),
+/// Named fields of a struct or enum variant.
+///
+/// Tuple and unit structs error with `Problem::UnnamedField` at the call site.
+pub fn named_fields(fields: &Fields) -> Result<&FieldsNamed> {
+    match fields {
+        Fields::Named(fields) => Ok(fields),
         Fields::Unnamed(_) | Fields::Unit => Err(
             Error::new(Span::call_site(), Problem::UnnamedField)
         ),
     }
 }
 
+/// Struct data of the derive input.
+///
+/// Enums and unions error with `Problem::NotNamedStruct`, spanning the whole input. Use
+/// `named_fields` afterwards to reject tuple and unit structs.
 pub fn named_struct<'a>(node: &'a DeriveInput) -> Result<&'a DataStruct> {
     match node.data {
         Data::Struct(ref structure) => Ok(structure),
@@ -25,3 +49,206 @@ pub fn named_struct<'a>(node: &'a DeriveInput) -> Result<&'a DataStruct> {
         ),
     }
 }
+
+/// Fields of the derive input and, for an enum of a single variant, that variant's name.
+///
+/// A single-variant enum is as a struct of its variant's fields. Other enums and unions
+/// error as in `named_struct`.
+pub fn struct_or_variant(node: &DeriveInput) -> Result<(&Fields, Option<&Ident>)> {
+    match &node.data {
+        Data::Enum(data) if data.variants.len() == 1 => {
+            let variant = &data.variants[0];
+            Ok((&variant.fields, Some(&variant.ident)))
+        },
+        _ => named_struct(node).map(|structure| (&structure.fields, None)),
+    }
+}
+
+/// Whether any of the fields is `#[deprecated]`.
+///
+/// Generated items reading or building such a field `#[allow(deprecated)]`, so only
+/// callers of a deprecated getter are warned, not the derive's own code.
+pub fn any_deprecated(fields: &Fields) -> bool {
+    fields
+        .iter()
+        .any(|field| field.attrs.iter().any(|attr| attr.path().is_ident("deprecated")))
+}
+
+/// A lifetime for the derive to introduce, eg; `'getter`.
+///
+/// Underscores are appended to the name while the struct has a lifetime of that name, so
+/// `'getter` becomes `'getter_` rather than shadowing the struct's own.
+pub fn fresh_lifetime(name: &str, generics: &Generics) -> Lifetime {
+    let mut name = name.to_owned();
+    while generics.lifetimes().any(|param| param.lifetime.ident == name) {
+        name.push('_');
+    }
+
+    Lifetime::new(format!("'{}", name).as_str(), Span::call_site())
+}
+
+/// Parse the name following `rename =`. Either a string literal or a bare identifier.
+/// The name spans the literal so errors about it point there. A literal is parsed as an
+/// identifier too, so raw identifiers such as `"r#move"` work and keywords are rejected.
+pub fn new_name(input: ParseStream) -> Result<Ident> {
+    if input.peek(LitStr) {
+        input.parse::<LitStr>()?.parse::<Ident>()
+    } else {
+        input.parse::<Ident>()
+    }
+}
+
+/// `CamelCase` name in `snake_case`, eg; `HttpRequest` becomes `http_request`.
+///
+/// A run of capitals is kept together as one word, so `HTTPServer` becomes
+/// `http_server` rather than `h_t_t_p_server`.
+pub fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::with_capacity(name.len() + 4);
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let previous = i.checked_sub(1).map(|p| chars[p]);
+            let next = chars.get(i + 1);
+            let boundary = match previous {
+                None | Some('_') => false,
+                Some(p) if p.is_uppercase() => matches!(next, Some(n) if n.is_lowercase()),
+                Some(_) => true,
+            };
+            if boundary {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+
+    snake
+}
+
+/// Levenshtein distance between two strings. Used for "did you mean" suggestions.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = previous[j] + if ca == *cb { 0 } else { 1 };
+            current[j + 1] = substitute
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Closest candidate to `word` if it is within a couple of edits.
+pub fn suggest(word: &str, candidates: &[&'static str]) -> Option<&'static str> {
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(word, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn named() -> Result<()> {
+        let node: DeriveInput = syn::parse_str("struct Named { a: u64, b: String }")?;
+        let fields = named_fields(&named_struct(&node)?.fields)?;
+        assert!(fields.named.len() == 2);
+
+        let node: DeriveInput = syn::parse_str("struct Unnamed(u64, String);")?;
+        let error = named_fields(&named_struct(&node)?.fields).unwrap_err();
+        assert!(error.to_string() == Problem::UnnamedField.to_string());
+
+        let node: DeriveInput = syn::parse_str("struct Unit;")?;
+        assert!(named_fields(&named_struct(&node)?.fields).is_err());
+
+        let node: DeriveInput = syn::parse_str("enum Enum { A, B }")?;
+        let error = named_struct(&node).unwrap_err();
+        let check = Problem::NotNamedStruct(StructIs::Enum).to_string();
+        assert!(error.to_string() == check);
+
+        let error = struct_or_variant(&node).unwrap_err();
+        assert!(error.to_string() == check);
+
+        let node: DeriveInput = syn::parse_str("enum Enum { A { a: u64 } }")?;
+        let (fields, variant) = struct_or_variant(&node)?;
+        assert!(named_fields(fields)?.named.len() == 1);
+        assert!(variant.map(|variant| variant == "A") == Some(true));
+
+        let node: DeriveInput = syn::parse_str("union Union { a: u64, b: f64 }")?;
+        let error = named_struct(&node).unwrap_err();
+        let check = Problem::NotNamedStruct(StructIs::Union).to_string();
+        assert!(error.to_string() == check);
+
+        Ok(())
+    }
+
+    #[test]
+    fn names() -> Result<()> {
+        let name = syn::parse::Parser::parse_str(new_name, "\"shatter\"")?;
+        assert!(name == "shatter");
+
+        let name = syn::parse::Parser::parse_str(new_name, "\"r#move\"")?;
+        assert!(name == "r#move");
+
+        let name = syn::parse::Parser::parse_str(new_name, "r#move")?;
+        assert!(name == "r#move");
+
+        assert!(syn::parse::Parser::parse_str(new_name, "\"move\"").is_err());
+        assert!(syn::parse::Parser::parse_str(new_name, "\"two words\"").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn snake() {
+        assert!(snake_case("Number") == "number");
+        assert!(snake_case("HttpRequest") == "http_request");
+        assert!(snake_case("already_snake") == "already_snake");
+        assert!(snake_case("FirstName") == "first_name");
+        assert!(snake_case("HTTPServer") == "http_server");
+        assert!(snake_case("ServerHTTP") == "server_http");
+        assert!(snake_case("Utf8Decoder") == "utf8_decoder");
+        assert!(snake_case("Mixed_Case") == "mixed_case");
+    }
+
+    #[test]
+    fn distance() {
+        assert!(edit_distance("skip", "skip") == 0);
+        assert!(edit_distance("skp", "skip") == 1);
+        assert!(edit_distance("renmae", "rename") == 2);
+        assert!(edit_distance("", "rename") == 6);
+    }
+
+    #[test]
+    fn suggestion() {
+        let known = ["skip", "rename"];
+        assert!(suggest("skp", &known) == Some("skip"));
+        assert!(suggest("renmae", &known) == Some("rename"));
+        assert!(suggest("complete_nonsense", &known).is_none());
+    }
+
+    #[test]
+    fn fresh() -> Result<()> {
+        let node: DeriveInput = syn::parse_str("struct S<'a, T> { a: &'a T }")?;
+        assert!(fresh_lifetime("getter", &node.generics).to_string() == "'getter");
+
+        let node: DeriveInput = syn::parse_str(
+            "struct S<'getter, 'getter_> { a: &'getter str, b: &'getter_ str }"
+        )?;
+        assert!(fresh_lifetime("getter", &node.generics).to_string() == "'getter__");
+
+        Ok(())
+    }
+}
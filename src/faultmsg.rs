@@ -1,7 +1,7 @@
 //! Error type. 
 use std::fmt;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum StructIs {
     Unnamed,
     Enum,
@@ -20,9 +20,10 @@ impl fmt::Display for StructIs {
     }
 }
 
-// Almost an error type! But `syn` already has an error type so this just fills the
-// `T: Display` part to avoid strings littering the source.
-#[derive(Debug)]
+// `syn` already has an error type so this mostly fills the `T: Display` part to avoid
+// strings littering the source. It is a full error type too, so it can be compared and
+// reported on its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Problem {
     NotNamedStruct(StructIs),
     UnnamedField,
@@ -33,6 +34,61 @@ pub enum Problem {
     EmptyGrouping,
     TokensFollowSkip,
     TokensFollowNewName,
+    TokensFollowFlag(&'static str),
+    NotSliceable,
+    NotACell,
+    NotAMutex,
+    InvalidLock(String),
+    InvalidFieldNames(String),
+    IterFieldsEmpty,
+    IterFieldsMixed(String),
+    PositionalNamesCount(usize, usize),
+    NotSingleField(usize),
+    NotIterable,
+    NotIndexable,
+    NotManuallyDrop,
+    NotUnwrappable,
+    NotAnOption,
+    NotAResult,
+    NotToOwned,
+    NotAPinnedBox,
+    NotAOnceCell,
+    NotAWeak,
+    InvalidUpgrade(String),
+    NotATraitObject,
+    DerefTargetMissing,
+    NotAMap,
+    NotAnArray(&'static str),
+    ArrayLenNotLiteral,
+    NoTypeName,
+    InvalidDefaultMode(String, Option<&'static str>),
+    UnknownGetterOption(String, Option<&'static str>),
+    UnknownDissolveOption(String, Option<&'static str>),
+    IntoVariantPath(String),
+    VariantShapeMisplaced(&'static str),
+    LifetimeWithoutBorrow,
+    IntoVariantConflict(&'static str),
+    AliasWithoutRename,
+    GroupTailCount(usize, usize),
+    ConflictingOptions(&'static str, &'static str),
+    EmptyGroup,
+    GroupFieldMissing(String),
+    EmptyFlatten,
+    FlattenFieldMissing(String),
+    MisplacedOption(&'static str, &'static str),
+    ReconstructSkipped(&'static str),
+    DissolveNameCollidesWithField(String),
+    PartFieldMissing(String),
+    DuplicatePart(String),
+    DropFieldMissing(String),
+    DropFieldDissolved(String),
+    DuplicateGetter(String),
+    DuplicateOrder(usize),
+    FirstGetter(String),
+    InvalidCase(String, Option<&'static str>),
+    InvalidGetterName(String),
+    InvalidTemplate(String),
+    LikelyMisspelledAttribute(String),
     InvalidAttribute,
 }
 
@@ -60,9 +116,245 @@ impl fmt::Display for Problem {
             Self::TokensFollowNewName => {
                 write!(f, "no further tokens must follow new name")
             },
+            Self::TokensFollowFlag(flag) => {
+                write!(f, "tokens are not meant to follow {} attribute", flag)
+            },
+            Self::NotSliceable => {
+                write!(f, "as_slice can only be used on an array, `Vec` or `Box<[T]>` field")
+            },
+            Self::NotACell => {
+                write!(f, "cell_get can only be used on a `Cell` field")
+            },
+            Self::UnknownGetterOption(key, Some(suggestion)) => {
+                write!(f, "unknown getter option `{}`, did you mean `{}`?", key, suggestion)
+            },
+            Self::UnknownGetterOption(key, None) => {
+                write!(f, "unknown getter option `{}`", key)
+            },
+            Self::UnknownDissolveOption(key, Some(suggestion)) => {
+                write!(f, "unknown dissolve option `{}`, did you mean `{}`?", key, suggestion)
+            },
+            Self::UnknownDissolveOption(key, None) => {
+                write!(f, "unknown dissolve option `{}`", key)
+            },
+            Self::IntoVariantPath(path) => {
+                write!(
+                    f,
+                    "into_variant needs the enum and its variant, eg; `State::Done`, not `{}`",
+                    path,
+                )
+            },
+            Self::VariantShapeMisplaced(shape) => {
+                write!(f, "option `{}` needs into_variant to choose the variant's shape", shape)
+            },
+            Self::LifetimeWithoutBorrow => {
+                write!(f, "option `explicit_lifetime` needs by_ref or mut to name the borrow")
+            },
+            Self::GroupTailCount(tail, fields) => {
+                write!(f, "group_tail({}) needs between 1 and {} dissolved fields", tail, fields)
+            },
+            Self::IntoVariantConflict(option) => {
+                write!(f, "into_variant can't be combined with `{}`", option)
+            },
+            Self::AliasWithoutRename => {
+                write!(f, "option `alias` keeps `dissolve` beside a renamed method, add `rename`")
+            },
+            Self::ConflictingOptions(first, second) => {
+                write!(f, "getter options `{}` and `{}` can't be combined", first, second)
+            },
+            Self::EmptyGroup => {
+                write!(f, "group must be given a name followed by at least one field")
+            },
+            Self::GroupFieldMissing(field) => {
+                write!(f, "no field named `{}` to group", field)
+            },
+            Self::EmptyFlatten => {
+                write!(f, "flatten must list at least one inner field with its type")
+            },
+            Self::FlattenFieldMissing(field) => {
+                write!(f, "no field named `{}` to flatten", field)
+            },
+            Self::NotAMutex => {
+                write!(f, "lock can only be used on a `Mutex` field")
+            },
+            Self::InvalidLock(variant) => {
+                write!(f, "lock can only be set to \"try\", not \"{}\"", variant)
+            },
+            Self::InvalidFieldNames(value) => {
+                write!(f, "field_names can only be set to \"all\", not \"{}\"", value)
+            },
+            Self::IterFieldsEmpty => {
+                write!(f, "iter_fields needs a struct with at least one field")
+            },
+            Self::IterFieldsMixed(expected) => {
+                write!(f, "iter_fields needs every field to be of type `{}`", expected)
+            },
+            Self::NotSingleField(count) => {
+                write!(f, "AsMut needs a struct of exactly one field, not {}", count)
+            },
+            Self::PositionalNamesCount(names, fields) => {
+                write!(f, "positional_names has {} names for {} fields", names, fields)
+            },
+            Self::NotIterable => {
+                write!(f, "iter can only be used on a `Vec`, `HashMap` or `BTreeMap` field")
+            },
+            Self::NotIndexable => {
+                write!(f, "indexed can only be used on an array, `Vec`, `Box<[T]>` or `&[T]` field")
+            },
+            Self::MisplacedOption(option, place) => {
+                write!(f, "option `{}` can only be used on {}", option, place)
+            },
+            Self::ReconstructSkipped(option) => {
+                write!(f, "{} can't be used when fields are skipped", option)
+            },
+            Self::DissolveNameCollidesWithField(field) => {
+                write!(
+                    f,
+                    "field `{}` has the same name as the dissolve method, \
+                     change the method name with #[dissolve(rename = \"...\")]",
+                    field,
+                )
+            },
+            Self::DropFieldMissing(field) => {
+                write!(f, "no field named `{}` to drop first", field)
+            },
+            Self::DropFieldDissolved(field) => {
+                write!(
+                    f,
+                    "field `{}` is part of the dissolved tuple, only skipped fields can be dropped first",
+                    field,
+                )
+            },
+            Self::PartFieldMissing(field) => {
+                write!(f, "no field named `{}` to take into parts", field)
+            },
+            Self::DuplicatePart(field) => {
+                write!(f, "field `{}` is already taken into parts", field)
+            },
+            Self::DuplicateGetter(name) => {
+                write!(f, "getter `{}` is generated more than once", name)
+            },
+            Self::DuplicateOrder(order) => {
+                write!(f, "more than one getter is given `order = {}`", order)
+            },
+            Self::FirstGetter(name) => {
+                write!(f, "getter `{}` is first generated here", name)
+            },
+            Self::NotManuallyDrop => {
+                write!(f, "manually_drop can only be used on a `ManuallyDrop` field")
+            },
+            Self::NotUnwrappable => {
+                write!(f, "unwrap can only be used on an `Option` or `Result` field")
+            },
+            Self::NotToOwned => {
+                write!(
+                    f,
+                    "to_owned can only be used on a `&str`, `&[T]`, `&Path`, `&OsStr` or `&CStr` field",
+                )
+            },
+            Self::NoTypeName => {
+                write!(f, "rename_from_type needs a field type named by a path, eg; `Config`")
+            },
+            Self::ArrayLenNotLiteral => {
+                write!(f, "array_full needs the array's length to be an integer literal")
+            },
+            Self::NotAnArray(option) => {
+                write!(f, "{} can only be used on an array `[T; N]` field", option)
+            },
+            Self::NotAMap => {
+                write!(f, "map_access can only be used on a `HashMap` or `BTreeMap` field")
+            },
+            Self::NotAPinnedBox => {
+                write!(f, "pin_ref can only be used on a `Pin<Box<T>>` field")
+            },
+            Self::NotAWeak => {
+                write!(f, "upgrade can only be used on a `Weak` field")
+            },
+            Self::InvalidUpgrade(pointer) => {
+                write!(f, "upgrade can only be set to \"rc\" or \"arc\", not \"{}\"", pointer)
+            },
+            Self::NotAOnceCell => {
+                write!(f, "once can only be used on a `OnceCell` or `OnceLock` field")
+            },
+            Self::DerefTargetMissing => {
+                write!(f, "deref needs the target type, eg; `#[getter(deref = \"Inner\")]`")
+            },
+            Self::NotATraitObject => {
+                write!(f, "as_dyn needs a trait object type, eg; \"dyn Handler\"")
+            },
+            Self::NotAResult => {
+                write!(f, "result_split can only be used on a `Result` field")
+            },
+            Self::NotAnOption => {
+                write!(f, "as_ref can only be used on an `Option` field")
+            },
+            Self::InvalidDefaultMode(mode, Some(suggestion)) => {
+                write!(f, "unknown getter mode \"{}\", did you mean \"{}\"?", mode, suggestion)
+            },
+            Self::InvalidDefaultMode(mode, None) => {
+                write!(
+                    f,
+                    "unknown getter mode \"{}\", expected \"ref\", \"copy\", \"clone\" or \"as_ref\"",
+                    mode,
+                )
+            },
+            Self::InvalidCase(case, Some(suggestion)) => {
+                write!(f, "unknown case \"{}\", did you mean \"{}\"?", case, suggestion)
+            },
+            Self::InvalidCase(case, None) => {
+                write!(f, "unknown case \"{}\" for rename_all", case)
+            },
+            Self::InvalidTemplate(template) => {
+                write!(f, "rename template \"{}\" must contain exactly one `{{}}`", template)
+            },
+            Self::InvalidGetterName(name) => {
+                write!(f, "\"{}\" is not a valid getter name", name)
+            },
+            Self::LikelyMisspelledAttribute(name) => {
+                write!(f, "unknown attribute `{}`, did you mean `getter`?", name)
+            },
             Self::InvalidAttribute => {
                 write!(f, "invalid attribute")
             },
         }
     }
 }
+
+impl std::error::Error for Problem {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn compare() {
+        assert!(Problem::UnnamedField == Problem::UnnamedField);
+        assert!(Problem::UnnamedField != Problem::InvalidAttribute);
+        assert!(
+            Problem::NotNamedStruct(StructIs::Enum) != Problem::NotNamedStruct(StructIs::Union)
+        );
+
+        let problem = Problem::GroupFieldMissing("a".to_owned());
+        assert!(problem.clone() == problem);
+        assert!(problem != Problem::GroupFieldMissing("b".to_owned()));
+    }
+
+    #[test]
+    fn format() {
+        let problem = Problem::NotNamedStruct(StructIs::Unit);
+        assert!(problem.to_string() == "type must be a named struct, not a unit struct");
+
+        let problem = Problem::UnknownGetterOption("skp".to_owned(), Some("skip"));
+        assert!(problem.to_string() == "unknown getter option `skp`, did you mean `skip`?");
+
+        let problem = Problem::InvalidTemplate("value".to_owned());
+        assert!(problem.to_string() == "rename template \"value\" must contain exactly one `{}`");
+    }
+
+    #[test]
+    fn error() {
+        let error: Box<dyn std::error::Error> = Box::new(Problem::EmptyGroup);
+        assert!(error.to_string() == Problem::EmptyGroup.to_string());
+        assert!(error.source().is_none());
+    }
+}
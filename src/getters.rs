@@ -1,200 +1,4059 @@
 //! Getters internals
 use std::convert::TryFrom;
 
-use proc_macro2::{TokenStream, Span};
-use quote::quote;
+use proc_macro2::{TokenStream, Span, Literal};
+use quote::{quote, quote_spanned, format_ident};
 use syn::{
     DeriveInput,
+    Fields,
     FieldsNamed,
+    Member,
+    Index,
     Type,
+    Expr,
+    Meta,
+    Lifetime,
+    LifetimeParam,
+    Visibility,
+    WhereClause,
+    WherePredicate,
+    GenericParam,
     AttrStyle,
     Ident,
     LitStr,
+    LitInt,
     Result,
     Error,
     Attribute,
+    Path,
+    PathArguments,
+    GenericArgument,
+    parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned,
     parse::{Parse, ParseStream},
 };
 
 use crate::{
-    extract::{named_fields, named_struct},
+    extract::{
+        any_deprecated,
+        fresh_lifetime,
+        named_fields,
+        new_name,
+        snake_case,
+        struct_or_variant,
+        suggest,
+    },
     faultmsg::Problem,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum Action {    
-    Skip,
+    Skip(Option<String>),
     Rename(Ident),
+    Alias(Ident),
+    AsSlice,
+    CellGet,
+    TryInto(Box<Type>),
+    AsDyn(Box<Type>),
+    Deref(Box<Type>),
+    Body(Box<Expr>, Box<Type>),
+    Lock { try_lock: bool },
+    Iter,
+    Indexed,
+    Eq,
+    Replace,
+    Take,
+    Consume,
+    Hash,
+    GatLifetime,
+    ArrayFull,
+    ResultSplit,
+    Ptr { mutable: bool },
+    ManuallyDrop,
+    Unwrap,
+    Ref,
+    Copy,
+    Clone,
+    AsRef,
+    ToOwned,
+    PinRef,
+    Once,
+    /// Whether the pointer is an `Rc` rather than an `Arc`, if given rather than inferred.
+    Upgrade { rc: Option<bool> },
+    Map(Ident, Box<Type>),
+    DocAlias(String),
+    Keep,
+    MapAccess,
+    RenameFromType,
+    ConstLen,
+    Bound(Vec<WherePredicate>),
+    Order(LitInt),
+}
+
+/// Every option accepted within `#[getter(...)]`.
+const OPTIONS: &[&str] = &[
+    "skip", "rename", "alias", "as_slice", "cell_get", "try_into", "as_dyn", "deref",
+    "body", "lock", "iter", "indexed", "eq", "replace", "take", "consume", "hash",
+    "gat_lifetime", "array_full", "result_split", "ptr",
+    "ptr_mut", "manually_drop", "unwrap", "ref", "copy", "clone", "as_ref", "to_owned",
+    "pin_ref", "once", "upgrade", "map", "doc_alias", "keep",
+    "map_access", "rename_from_type", "const_len", "bound", "order",
+];
+
+/// Values accepted by the struct level `#[getter(all = "...")]`.
+const DEFAULT_MODES: &[&str] = &["ref", "copy", "clone", "as_ref"];
+
+/// Parse an option that takes no further tokens.
+fn flag(input: ParseStream, action: Action, name: &'static str) -> Result<Action> {
+    if !input.is_empty() {
+        Err(Error::new(Span::call_site(), Problem::TokensFollowFlag(name)))
+    } else {
+        Ok(action)
+    }
 }
 
 impl Parse for Action {
     fn parse(input: ParseStream) -> Result<Self> {
         syn::custom_keyword!(skip);
         syn::custom_keyword!(rename);
+        syn::custom_keyword!(alias);
+        syn::custom_keyword!(as_slice);
+        syn::custom_keyword!(cell_get);
+        syn::custom_keyword!(try_into);
+        syn::custom_keyword!(as_dyn);
+        syn::custom_keyword!(deref);
+        syn::custom_keyword!(body);
+        syn::custom_keyword!(lock);
+        syn::custom_keyword!(iter);
+        syn::custom_keyword!(indexed);
+        syn::custom_keyword!(eq);
+        syn::custom_keyword!(replace);
+        syn::custom_keyword!(consume);
+        syn::custom_keyword!(take);
+        syn::custom_keyword!(hash);
+        syn::custom_keyword!(gat_lifetime);
+        syn::custom_keyword!(array_full);
+        syn::custom_keyword!(result_split);
+        syn::custom_keyword!(ptr);
+        syn::custom_keyword!(ptr_mut);
+        syn::custom_keyword!(manually_drop);
+        syn::custom_keyword!(unwrap);
+        syn::custom_keyword!(copy);
+        syn::custom_keyword!(clone);
+        syn::custom_keyword!(as_ref);
+        syn::custom_keyword!(to_owned);
+        syn::custom_keyword!(pin_ref);
+        syn::custom_keyword!(once);
+        syn::custom_keyword!(upgrade);
+        syn::custom_keyword!(map);
+        syn::custom_keyword!(map_ty);
+        syn::custom_keyword!(doc_alias);
+        syn::custom_keyword!(keep);
+        syn::custom_keyword!(map_access);
+        syn::custom_keyword!(rename_from_type);
+        syn::custom_keyword!(const_len);
+        syn::custom_keyword!(bound);
+        syn::custom_keyword!(order);
         
         if input.peek(skip) {
             let _ = input.parse::<skip>()?;
+            let reason = if input.peek(syn::Token![=]) {
+                let _ = input.parse::<syn::Token![=]>()?;
+                Some(input.parse::<LitStr>()?.value())
+            } else {
+                None
+            };
             if !input.is_empty() {
                 Err(Error::new(Span::call_site(), Problem::TokensFollowSkip))
             } else {
-                Ok(Action::Skip)
+                Ok(Action::Skip(reason))
             }
         } else if input.peek(rename) {
             let _ = input.parse::<rename>()?;
             let _ = input.parse::<syn::Token![=]>()?;
-            let name = input.parse::<LitStr>()?;
+            let name = new_name(input)?;
+            if !input.is_empty() {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowNewName))
+            } else {
+                Ok(Action::Rename(name))
+            }
+        } else if input.peek(alias) {
+            let _ = input.parse::<alias>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let name = new_name(input)?;
             if !input.is_empty() {
                 Err(Error::new(Span::call_site(), Problem::TokensFollowNewName))
             } else {
-                Ok(Action::Rename(Ident::new(name.value().as_str(), Span::call_site())))
+                Ok(Action::Alias(name))
+            }
+        } else if input.peek(as_slice) {
+            let _ = input.parse::<as_slice>()?;
+            flag(input, Action::AsSlice, "as_slice")
+        } else if input.peek(cell_get) {
+            let _ = input.parse::<cell_get>()?;
+            flag(input, Action::CellGet, "cell_get")
+        } else if input.peek(try_into) {
+            let _ = input.parse::<try_into>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let target = input.parse::<LitStr>()?.parse::<Type>()?;
+            if !input.is_empty() {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("try_into")))
+            } else {
+                Ok(Action::TryInto(Box::new(target)))
+            }
+        } else if input.peek(as_dyn) {
+            let _ = input.parse::<as_dyn>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let target = input.parse::<LitStr>()?;
+            let object = match target.parse::<Type>()? {
+                object @ Type::TraitObject(_) => object,
+                _ => return Err(Error::new(target.span(), Problem::NotATraitObject)),
+            };
+            flag(input, Action::AsDyn(Box::new(object)), "as_dyn")
+        } else if input.peek(deref) {
+            let _ = input.parse::<deref>()?;
+            // The target of a `Deref` impl can't be seen from the field's type.
+            if input.is_empty() {
+                return Err(Error::new(Span::call_site(), Problem::DerefTargetMissing));
+            }
+            let _ = input.parse::<syn::Token![=]>()?;
+            let target = input.parse::<LitStr>()?.parse::<Type>()?;
+            flag(input, Action::Deref(Box::new(target)), "deref")
+        } else if input.peek(body) {
+            let _ = input.parse::<body>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let (expr, ty) = typed_expr(input)?;
+            let _ = input.parse::<Option<syn::Token![,]>>()?;
+            if !input.is_empty() {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("ty")))
+            } else {
+                Ok(Action::Body(expr, ty))
+            }
+        } else if input.peek(lock) {
+            let _ = input.parse::<lock>()?;
+            if input.is_empty() {
+                return Ok(Action::Lock { try_lock: false });
+            }
+            let _ = input.parse::<syn::Token![=]>()?;
+            let variant = input.parse::<LitStr>()?;
+            if variant.value() != "try" {
+                Err(Error::new(variant.span(), Problem::InvalidLock(variant.value())))
+            } else if !input.is_empty() {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("lock")))
+            } else {
+                Ok(Action::Lock { try_lock: true })
+            }
+        } else if input.peek(bound) {
+            let _ = input.parse::<bound>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let clause = input.parse::<LitStr>()?.parse_with(where_clause)?;
+            flag(input, Action::Bound(clause.predicates.into_iter().collect()), "bound")
+        } else if input.peek(order) {
+            let _ = input.parse::<order>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let position = input.parse::<LitInt>()?;
+            let _ = position.base10_parse::<usize>()?;
+            flag(input, Action::Order(position), "order")
+        } else if input.peek(const_len) {
+            let _ = input.parse::<const_len>()?;
+            flag(input, Action::ConstLen, "const_len")
+        } else if input.peek(rename_from_type) {
+            let _ = input.parse::<rename_from_type>()?;
+            flag(input, Action::RenameFromType, "rename_from_type")
+        } else if input.peek(map_access) {
+            let _ = input.parse::<map_access>()?;
+            flag(input, Action::MapAccess, "map_access")
+        } else if input.peek(keep) {
+            let _ = input.parse::<keep>()?;
+            flag(input, Action::Keep, "keep")
+        } else if input.peek(doc_alias) {
+            let _ = input.parse::<doc_alias>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let alias = input.parse::<LitStr>()?.value();
+            flag(input, Action::DocAlias(alias), "doc_alias")
+        } else if input.peek(iter) {
+            let _ = input.parse::<iter>()?;
+            flag(input, Action::Iter, "iter")
+        } else if input.peek(indexed) {
+            let _ = input.parse::<indexed>()?;
+            flag(input, Action::Indexed, "indexed")
+        } else if input.peek(eq) {
+            let _ = input.parse::<eq>()?;
+            flag(input, Action::Eq, "eq")
+        } else if input.peek(replace) {
+            let _ = input.parse::<replace>()?;
+            flag(input, Action::Replace, "replace")
+        } else if input.peek(take) {
+            let _ = input.parse::<take>()?;
+            flag(input, Action::Take, "take")
+        } else if input.peek(consume) {
+            let _ = input.parse::<consume>()?;
+            flag(input, Action::Consume, "consume")
+        } else if input.peek(hash) {
+            let _ = input.parse::<hash>()?;
+            flag(input, Action::Hash, "hash")
+        } else if input.peek(gat_lifetime) {
+            let _ = input.parse::<gat_lifetime>()?;
+            flag(input, Action::GatLifetime, "gat_lifetime")
+        } else if input.peek(array_full) {
+            let _ = input.parse::<array_full>()?;
+            flag(input, Action::ArrayFull, "array_full")
+        } else if input.peek(result_split) {
+            let _ = input.parse::<result_split>()?;
+            flag(input, Action::ResultSplit, "result_split")
+        } else if input.peek(ptr) {
+            let _ = input.parse::<ptr>()?;
+            flag(input, Action::Ptr { mutable: false }, "ptr")
+        } else if input.peek(ptr_mut) {
+            let _ = input.parse::<ptr_mut>()?;
+            flag(input, Action::Ptr { mutable: true }, "ptr_mut")
+        } else if input.peek(manually_drop) {
+            let _ = input.parse::<manually_drop>()?;
+            flag(input, Action::ManuallyDrop, "manually_drop")
+        } else if input.peek(unwrap) {
+            let _ = input.parse::<unwrap>()?;
+            flag(input, Action::Unwrap, "unwrap")
+        } else if input.peek(syn::Token![ref]) {
+            let _ = input.parse::<syn::Token![ref]>()?;
+            flag(input, Action::Ref, "ref")
+        } else if input.peek(copy) {
+            let _ = input.parse::<copy>()?;
+            flag(input, Action::Copy, "copy")
+        } else if input.peek(clone) {
+            let _ = input.parse::<clone>()?;
+            flag(input, Action::Clone, "clone")
+        } else if input.peek(as_ref) {
+            let _ = input.parse::<as_ref>()?;
+            flag(input, Action::AsRef, "as_ref")
+        } else if input.peek(to_owned) {
+            let _ = input.parse::<to_owned>()?;
+            flag(input, Action::ToOwned, "to_owned")
+        } else if input.peek(pin_ref) {
+            let _ = input.parse::<pin_ref>()?;
+            flag(input, Action::PinRef, "pin_ref")
+        } else if input.peek(once) {
+            let _ = input.parse::<once>()?;
+            flag(input, Action::Once, "once")
+        } else if input.peek(upgrade) {
+            let _ = input.parse::<upgrade>()?;
+            if input.is_empty() {
+                return Ok(Action::Upgrade { rc: None });
+            }
+            let _ = input.parse::<syn::Token![=]>()?;
+            let pointer = input.parse::<LitStr>()?;
+            let rc = match pointer.value().as_str() {
+                "rc" => true,
+                "arc" => false,
+                other => return Err(Error::new(
+                    pointer.span(),
+                    Problem::InvalidUpgrade(other.to_owned()),
+                )),
+            };
+            flag(input, Action::Upgrade { rc: Some(rc) }, "upgrade")
+        } else if input.peek(map) {
+            let _ = input.parse::<map>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let method = input.parse::<LitStr>()?.parse::<Ident>()?;
+            let _ = input.parse::<syn::Token![,]>()?;
+            let _ = input.parse::<map_ty>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let ty = input.parse::<LitStr>()?.parse::<Type>()?;
+            if !input.is_empty() {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("map_ty")))
+            } else {
+                Ok(Action::Map(method, Box::new(ty)))
             }
+        } else if input.peek(Ident) {
+            let key = input.parse::<Ident>()?;
+            let name = key.to_string();
+            let suggestion = suggest(name.as_str(), OPTIONS);
+            Err(Error::new(key.span(), Problem::UnknownGetterOption(name, suggestion)))
         } else {
             Err(Error::new(Span::call_site(), Problem::InvalidAttribute))
         }
     }
 }
 
-fn get_action_from(attributes: &[Attribute]) -> Result<Option<Action>> {
-    let mut current: Option<Action> = None;
+fn get_actions_from(attributes: &[Attribute]) -> Result<Vec<Action>> {
+    let mut actions: Vec<Action> = Vec::new();
     
     for attr in attributes {
         if attr.style != AttrStyle::Outer { continue; }
         
         if attr.path().is_ident("getter") {
-            current = Some(attr.parse_args::<Action>()?);
+            actions.push(attr.parse_args::<Action>()?);
         }
     }
     
-    Ok(current)
+    Ok(actions)
 }
 
-pub struct Field {
-    ty: Type,    
-    name: Ident,
-    getter: Ident,
+/// Attributes whose name is a typo of `getter` would otherwise be silently ignored. Only
+/// known typos are caught, `getter` or `getters` in another case or broken by
+/// underscores. Closely named attributes of other crates, such as `setter`, are left be.
+fn check_misspelled(attributes: &[Attribute]) -> Result<()> {
+    let misspelled = attributes
+        .iter()
+        .filter_map(|attr| attr.path().get_ident())
+        .find(|ident| *ident != "getter" && is_getter_typo(ident.to_string().as_str()));
+
+    match misspelled {
+        Some(ident) => Err(Error::new(
+            ident.span(),
+            Problem::LikelyMisspelledAttribute(ident.to_string()),
+        )),
+        None => Ok(()),
+    }
 }
 
-impl Field {
-    fn from_field(field: &syn::Field) -> Result<Option<Self>> {
-        let name: Ident =  field.ident
-            .clone()
-            .ok_or(Error::new(Span::call_site(), Problem::UnnamedField))?;
-        
-        match get_action_from(field.attrs.as_slice())? {
-            Some(Action::Skip) => return Ok(None),
-            Some(Action::Rename(ident)) => Ok(Some(Field {
-                ty: field.ty.clone(),
-                name: name,
-                getter: ident,
-            })),
-            None => Ok(Some(Field {
-                ty: field.ty.clone(),
-                name: name.clone(),
-                getter: name,
-            })),
+fn is_getter_typo(name: &str) -> bool {
+    let squashed: String = name
+        .chars()
+        .filter(|c| *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect();
+
+    squashed == "getter" || squashed == "getters"
+}
+
+/// Type parameters of `ty` if it is a path ending in `wrapper`, eg; `Vec<T>` or
+/// `std::cell::Cell<T>`.
+fn type_arguments<'a>(ty: &'a Type, wrapper: &str) -> Option<Vec<&'a Type>> {
+    let tp = match ty {
+        Type::Path(tp) if tp.qself.is_none() => tp,
+        _ => return None,
+    };
+
+    let segment = tp.path.segments.last()?;
+    if segment.ident != wrapper { return None; }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args.args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(Some(ty)),
+                GenericArgument::Lifetime(_) => None,
+                _ => Some(None),
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
+/// The `T` of a single parameter wrapper type such as `Vec<T>`.
+fn wrapped<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    match type_arguments(ty, wrapper)?.as_slice() {
+        [inner] => Some(inner),
+        _ => None,
+    }
+}
+
+/// Element type of an array `[T; N]`, a `Vec<T>` or a boxed slice `Box<[T]>`.
+fn slice_element(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Array(ta) => Some(&ta.elem),
+        _ => match wrapped(ty, "Box") {
+            Some(Type::Slice(ts)) => Some(&ts.elem),
+            Some(_) => None,
+            None => wrapped(ty, "Vec"),
+        },
+    }
+}
+
+/// Element type of a field that can be indexed with `get`, as `slice_element` or a
+/// borrowed slice `&[T]`.
+fn indexed_element(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Reference(tr) => match tr.elem.as_ref() {
+            Type::Slice(ts) => Some(&ts.elem),
+            _ => None,
+        },
+        _ => slice_element(ty),
+    }
+}
+
+/// The `std` crate for the few options that need it, spanned at `span`. In a `no_std`
+/// crate it then fails to resolve at the field using such an option.
+fn std_at(span: Span) -> TokenStream {
+    quote_spanned!(span=> ::std)
+}
+
+/// Owned counterpart of a borrowed field type, eg; `String` for `&str`.
+fn owned_target(ty: &Type) -> Option<Type> {
+    let std = std_at(ty.span());
+    let elem = match ty {
+        Type::Reference(tr) if tr.mutability.is_none() => tr.elem.as_ref(),
+        _ => return None,
+    };
+
+    match elem {
+        Type::Slice(ts) => {
+            let item = &ts.elem;
+            Some(parse_quote!(#std::vec::Vec<#item>))
+        },
+        Type::Path(tp) if tp.qself.is_none() => {
+            let last = tp.path.segments.last()?;
+            if !last.arguments.is_empty() {
+                return None;
+            }
+            match last.ident.to_string().as_str() {
+                "str" => Some(parse_quote!(#std::string::String)),
+                "Path" => Some(parse_quote!(#std::path::PathBuf)),
+                "OsStr" => Some(parse_quote!(#std::ffi::OsString)),
+                "CStr" => Some(parse_quote!(#std::ffi::CString)),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Options given in a `#[getter(...)]` attribute on the struct itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum StructAction {
+    Group(Ident, Vec<Ident>),
+    Flatten(Flatten),
+    Expr(Computed),
+    KeepEmptyImpl,
+    DocExamples,
+    Cfg(Box<Meta>),
+    FreeFn,
+    OverrideWhere(Box<WhereClause>),
+    All(Box<Action>),
+    Vis(Box<Visibility>),
+    Prefix(String),
+    RenameAll(Renamer),
+    Strict,
+    New(Ident),
+    DefaultFn(Ident),
+    Snapshot(Ident, Box<Path>),
+    FieldNames { all: bool },
+    EqAll,
+    StripPrefix(String),
+    IterFields,
+    PositionalNames(Vec<LitStr>),
+    ByValueSelf,
+    MergeBounds,
+}
+
+/// Every option accepted within a struct level `#[getter(...)]`.
+const STRUCT_OPTIONS: &[&str] = &[
+    "group", "flatten", "expr", "keep_empty_impl", "doc_examples", "cfg", "free_fn",
+    "override_where", "all", "vis", "prefix", "rename_all", "strict", "new",
+    "default_fn", "snapshot", "field_names", "eq_all",
+    "strip_prefix", "iter_fields", "positional_names", "by_value_self",
+    "merge_bounds",
+];
+
+/// Whether a struct level option has been fully parsed. Options are separated by commas.
+fn ended(input: ParseStream) -> bool {
+    input.is_empty() || input.peek(syn::Token![,])
+}
+
+/// Case conventions accepted by `#[getter(rename_all = "...")]`, named as in serde.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Case {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+}
+
+const CASES: &[&str] = &[
+    "lowercase", "UPPERCASE", "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE",
+];
+
+/// How `#[getter(rename_all = "...")]` renames every field getter, either a case
+/// convention or a `template:` with a `{}` placeholder for the field name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Renamer {
+    Case(Case),
+    Template(String),
+}
+
+impl Renamer {
+    fn apply(&self, name: &str) -> String {
+        match self {
+            Renamer::Case(case) => case.apply(name),
+            Renamer::Template(template) => template.replacen("{}", name, 1),
         }
     }
-    
-    fn from_fields_named(fields_named: &FieldsNamed) -> Result<Vec<Self>> {
-        fields_named.named
-            .iter()
-            .try_fold(Vec::new(), |mut fields, field| {
-                if let Some(field) = Field::from_field(field)? {
-                    fields.push(field);
-                }
 
-                Ok(fields)
-            })
+    fn is_snake(&self) -> bool {
+        match self {
+            Renamer::Case(case) => case.is_snake(),
+            Renamer::Template(template) => !template.chars().any(char::is_uppercase),
+        }
     }
+}
 
-    fn emit(&self) -> TokenStream {
-        let returns = &self.ty;
-        let field_name = &self.name;
-        let getter_name = &self.getter;
-        
-        match &self.ty {
-            Type::Reference(tr) => {
-                let lifetime = tr.lifetime.as_ref();
-                quote!(
-                    pub fn #getter_name(&#lifetime self) -> #returns {
-                        self.#field_name
-                    }
-                )
-            },
-            _ => {
-                quote!(
-                    pub fn #getter_name(&self) -> &#returns {
-                        &self.#field_name
-                    }
-                )
+impl Case {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "lowercase" => Some(Case::Lower),
+            "UPPERCASE" => Some(Case::Upper),
+            "PascalCase" => Some(Case::Pascal),
+            "camelCase" => Some(Case::Camel),
+            "snake_case" => Some(Case::Snake),
+            "SCREAMING_SNAKE_CASE" => Some(Case::ScreamingSnake),
+            _ => None,
+        }
+    }
+
+    /// Whether names in this case pass the `non_snake_case` lint.
+    fn is_snake(&self) -> bool {
+        matches!(self, Case::Lower | Case::Snake)
+    }
+
+    /// A field name in this case. Field names are taken to be made of `_` separated words.
+    fn apply(&self, name: &str) -> String {
+        let snake = snake_case(name);
+        let capitalized = || snake
+            .split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                    .unwrap_or_default()
+            });
+
+        match self {
+            Case::Lower => name.to_lowercase(),
+            Case::Upper => name.to_uppercase(),
+            Case::Pascal => capitalized().collect(),
+            Case::Camel => {
+                let pascal: String = capitalized().collect();
+                let mut chars = pascal.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_lowercase().chain(chars).collect())
+                    .unwrap_or_default()
             },
+            Case::Snake => snake,
+            Case::ScreamingSnake => snake.to_uppercase(),
         }
     }
 }
 
-pub struct NamedStruct<'a> {
-    original: &'a DeriveInput,
+impl Parse for StructAction {
+    fn parse(input: ParseStream) -> Result<Self> {
+        syn::custom_keyword!(group);
+        syn::custom_keyword!(flatten);
+        syn::custom_keyword!(expr);
+        syn::custom_keyword!(keep_empty_impl);
+        syn::custom_keyword!(doc_examples);
+        syn::custom_keyword!(cfg);
+        syn::custom_keyword!(free_fn);
+        syn::custom_keyword!(override_where);
+        syn::custom_keyword!(all);
+        syn::custom_keyword!(vis);
+        syn::custom_keyword!(prefix);
+        syn::custom_keyword!(rename_all);
+        syn::custom_keyword!(strict);
+        syn::custom_keyword!(new);
+        syn::custom_keyword!(default_fn);
+        syn::custom_keyword!(snapshot);
+        syn::custom_keyword!(field_names);
+        syn::custom_keyword!(eq_all);
+        syn::custom_keyword!(strip_prefix);
+        syn::custom_keyword!(iter_fields);
+        syn::custom_keyword!(positional_names);
+        syn::custom_keyword!(by_value_self);
+        syn::custom_keyword!(merge_bounds);
+
+        if input.peek(group) {
+            let _ = input.parse::<group>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let mut names = Punctuated::<Ident, syn::Token![,]>::parse_terminated(&content)?
+                .into_iter();
+            let name = names
+                .next()
+                .ok_or(Error::new(Span::call_site(), Problem::EmptyGroup))?;
+            let fields: Vec<Ident> = names.collect();
+            if fields.is_empty() {
+                Err(Error::new(name.span(), Problem::EmptyGroup))
+            } else if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("group")))
+            } else {
+                Ok(StructAction::Group(name, fields))
+            }
+        } else if input.peek(flatten) {
+            let _ = input.parse::<flatten>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let flattened = content.parse::<Flatten>()?;
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("flatten")))
+            } else {
+                Ok(StructAction::Flatten(flattened))
+            }
+        } else if input.peek(expr) {
+            let _ = input.parse::<expr>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let computed = content.parse::<Computed>()?;
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("expr")))
+            } else {
+                Ok(StructAction::Expr(computed))
+            }
+        } else if input.peek(keep_empty_impl) {
+            let _ = input.parse::<keep_empty_impl>()?;
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("keep_empty_impl")))
+            } else {
+                Ok(StructAction::KeepEmptyImpl)
+            }
+        } else if input.peek(doc_examples) {
+            let _ = input.parse::<doc_examples>()?;
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("doc_examples")))
+            } else {
+                Ok(StructAction::DocExamples)
+            }
+        } else if input.peek(cfg) {
+            let _ = input.parse::<cfg>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let predicate = input.parse::<LitStr>()?.parse::<Meta>()?;
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("cfg")))
+            } else {
+                Ok(StructAction::Cfg(Box::new(predicate)))
+            }
+        } else if input.peek(new) {
+            let keyword = input.parse::<new>()?;
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("new")))
+            } else {
+                Ok(StructAction::New(Ident::new("new", keyword.span)))
+            }
+        } else if input.peek(snapshot) {
+            let keyword = input.parse::<snapshot>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let path = input.parse::<LitStr>()?.parse::<Path>()?;
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("snapshot")))
+            } else {
+                Ok(StructAction::Snapshot(
+                    Ident::new("snapshot", keyword.span),
+                    Box::new(path),
+                ))
+            }
+        } else if input.peek(default_fn) {
+            let keyword = input.parse::<default_fn>()?;
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("default_fn")))
+            } else {
+                Ok(StructAction::DefaultFn(Ident::new("with_defaults", keyword.span)))
+            }
+        } else if input.peek(strict) {
+            let _ = input.parse::<strict>()?;
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("strict")))
+            } else {
+                Ok(StructAction::Strict)
+            }
+        } else if input.peek(free_fn) {
+            let _ = input.parse::<free_fn>()?;
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("free_fn")))
+            } else {
+                Ok(StructAction::FreeFn)
+            }
+        } else if input.peek(override_where) {
+            let _ = input.parse::<override_where>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let clause = input.parse::<LitStr>()?.parse_with(where_clause)?;
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("override_where")))
+            } else {
+                Ok(StructAction::OverrideWhere(Box::new(clause)))
+            }
+        } else if input.peek(all) {
+            let _ = input.parse::<all>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let value = input.parse::<LitStr>()?;
+            let default = match value.value().as_str() {
+                "ref" => Action::Ref,
+                "copy" => Action::Copy,
+                "clone" => Action::Clone,
+                "as_ref" => Action::AsRef,
+                other => {
+                    let suggestion = suggest(other, DEFAULT_MODES);
+                    return Err(Error::new(
+                        value.span(),
+                        Problem::InvalidDefaultMode(other.to_owned(), suggestion),
+                    ));
+                },
+            };
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("all")))
+            } else {
+                Ok(StructAction::All(Box::new(default)))
+            }
+        } else if input.peek(vis) {
+            let _ = input.parse::<vis>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let visibility = input.parse::<LitStr>()?.parse::<Visibility>()?;
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("vis")))
+            } else {
+                Ok(StructAction::Vis(Box::new(visibility)))
+            }
+        } else if input.peek(strip_prefix) {
+            let _ = input.parse::<strip_prefix>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let value = input.parse::<LitStr>()?.value();
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("strip_prefix")))
+            } else {
+                Ok(StructAction::StripPrefix(value))
+            }
+        } else if input.peek(prefix) {
+            let _ = input.parse::<prefix>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let value = input.parse::<LitStr>()?.value();
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("prefix")))
+            } else {
+                Ok(StructAction::Prefix(value))
+            }
+        } else if input.peek(positional_names) {
+            let _ = input.parse::<positional_names>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let content;
+            syn::bracketed!(content in input);
+            let names = Punctuated::<LitStr, syn::Token![,]>::parse_terminated(&content)?;
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("positional_names")))
+            } else {
+                Ok(StructAction::PositionalNames(names.into_iter().collect()))
+            }
+        } else if input.peek(iter_fields) {
+            let _ = input.parse::<iter_fields>()?;
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("iter_fields")))
+            } else {
+                Ok(StructAction::IterFields)
+            }
+        } else if input.peek(by_value_self) {
+            let _ = input.parse::<by_value_self>()?;
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("by_value_self")))
+            } else {
+                Ok(StructAction::ByValueSelf)
+            }
+        } else if input.peek(merge_bounds) {
+            let _ = input.parse::<merge_bounds>()?;
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("merge_bounds")))
+            } else {
+                Ok(StructAction::MergeBounds)
+            }
+        } else if input.peek(eq_all) {
+            let _ = input.parse::<eq_all>()?;
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("eq_all")))
+            } else {
+                Ok(StructAction::EqAll)
+            }
+        } else if input.peek(field_names) {
+            let _ = input.parse::<field_names>()?;
+            if ended(input) {
+                return Ok(StructAction::FieldNames { all: false });
+            }
+            let _ = input.parse::<syn::Token![=]>()?;
+            let value = input.parse::<LitStr>()?;
+            if value.value() != "all" {
+                Err(Error::new(value.span(), Problem::InvalidFieldNames(value.value())))
+            } else if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("field_names")))
+            } else {
+                Ok(StructAction::FieldNames { all: true })
+            }
+        } else if input.peek(rename_all) {
+            let _ = input.parse::<rename_all>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let value = input.parse::<LitStr>()?;
+            let name = value.value();
+            let renamer = match name.strip_prefix("template:") {
+                Some(template) if template.matches("{}").count() == 1 => {
+                    Renamer::Template(template.to_owned())
+                },
+                Some(template) => return Err(Error::new(
+                    value.span(),
+                    Problem::InvalidTemplate(template.to_owned()),
+                )),
+                None => match Case::from_name(name.as_str()) {
+                    Some(case) => Renamer::Case(case),
+                    None => {
+                        let suggestion = suggest(name.as_str(), CASES);
+                        return Err(Error::new(
+                            value.span(),
+                            Problem::InvalidCase(name, suggestion),
+                        ));
+                    },
+                },
+            };
+            if !ended(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("rename_all")))
+            } else {
+                Ok(StructAction::RenameAll(renamer))
+            }
+        } else if input.peek(Ident) {
+            let key = input.parse::<Ident>()?;
+            let name = key.to_string();
+            let suggestion = suggest(name.as_str(), STRUCT_OPTIONS);
+            Err(Error::new(key.span(), Problem::UnknownGetterOption(name, suggestion)))
+        } else {
+            Err(Error::new(Span::call_site(), Problem::InvalidAttribute))
+        }
+    }
+}
+
+/// Where-clause predicates with or without the leading `where`, eg; `T: Clone, U: Copy`.
+fn where_clause(input: ParseStream) -> Result<WhereClause> {
+    let where_token = input
+        .parse::<Option<syn::Token![where]>>()?
+        .unwrap_or_default();
+    let predicates = Punctuated::<WherePredicate, syn::Token![,]>::parse_terminated(input)?;
+
+    Ok(WhereClause { where_token, predicates })
+}
+
+fn get_struct_actions_from(attributes: &[Attribute]) -> Result<Vec<StructAction>> {
+    let mut actions: Vec<StructAction> = Vec::new();
+
+    for attr in attributes {
+        if attr.style != AttrStyle::Outer { continue; }
+
+        if attr.path().is_ident("getter") {
+            let options = attr.parse_args_with(
+                Punctuated::<StructAction, syn::Token![,]>::parse_terminated
+            )?;
+            actions.extend(options);
+        }
+    }
+
+    Ok(actions)
+}
+
+/// A getter returning references to several fields at once.
+struct Group {
     name: Ident,
-    fields: Vec<Field>,
+    fields: Vec<(Ident, Type)>,
 }
 
-impl<'a> NamedStruct<'a> {
-    pub fn emit(&self) -> TokenStream {
-        let (impl_generics, struct_generics, where_clause) = self.original.generics
-            .split_for_impl();        
-        let struct_name = &self.name;
-        let methods: Vec<TokenStream> = self.fields
-            .iter()
-            .map(|field| field.emit())
-            .collect();
+impl Group {
+    fn resolve(name: Ident, members: Vec<Ident>, fields_named: &FieldsNamed) -> Result<Self> {
+        let fields = members
+            .into_iter()
+            .map(|member| {
+                fields_named.named
+                    .iter()
+                    .find(|field| field.ident.as_ref() == Some(&member))
+                    .map(|field| (member.clone(), field.ty.clone()))
+                    .ok_or(Error::new(
+                        member.span(),
+                        Problem::GroupFieldMissing(member.to_string()),
+                    ))
+            })
+            .collect::<Result<Vec<(Ident, Type)>>>()?;
+
+        Ok(Group { name, fields })
+    }
+
+    fn emit(&self, vis: &Visibility) -> TokenStream {
+        let group_name = &self.name;
+        let names = self.fields.iter().map(|(name, _)| name);
+        let types = self.fields.iter().map(|(_, ty)| ty);
 
         quote!(
-            impl #impl_generics #struct_name #struct_generics
-                #where_clause
-            {
-                #(#methods)*
+            #vis fn #group_name(&self) -> (#(&#types,)*) {
+                (#(&self.#names,)*)
             }
-        )        
+        )
     }
 }
 
-impl<'a> TryFrom<&'a DeriveInput> for NamedStruct<'a> {
-    type Error = Error;
-    
-    fn try_from(node: &'a DeriveInput) -> Result<Self> {
-        let struct_data = named_struct(node)?;
-        let named_fields = named_fields(struct_data)?;
-        let fields = Field::from_fields_named(named_fields)?;
+/// Getters reaching into a field of another struct, eg; `common: [a: u32, b: String]`.
+/// The inner struct can't be seen from the derive so its fields are listed with their types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Flatten {
+    field: Ident,
+    inner: Vec<(Ident, Type)>,
+}
 
-        Ok(NamedStruct {
-            original: node,
-            name: node.ident.clone(),
-            fields,
-        })
+impl Parse for Flatten {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let field = input.parse::<Ident>()?;
+        let _ = input.parse::<syn::Token![:]>()?;
+        let content;
+        syn::bracketed!(content in input);
+        let mut inner = Vec::new();
+        while !content.is_empty() {
+            let name = content.parse::<Ident>()?;
+            let _ = content.parse::<syn::Token![:]>()?;
+            let ty = content.parse::<Type>()?;
+            inner.push((name, ty));
+            if content.is_empty() {
+                break;
+            }
+            let _ = content.parse::<syn::Token![,]>()?;
+        }
+
+        if inner.is_empty() {
+            Err(Error::new(field.span(), Problem::EmptyFlatten))
+        } else if !input.is_empty() {
+            Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("flatten")))
+        } else {
+            Ok(Flatten { field, inner })
+        }
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+impl Flatten {
+    fn resolve(self, fields_named: &FieldsNamed) -> Result<Self> {
+        let exists = fields_named.named
+            .iter()
+            .any(|field| field.ident.as_ref() == Some(&self.field));
+        if exists {
+            Ok(self)
+        } else {
+            Err(Error::new(
+                self.field.span(),
+                Problem::FlattenFieldMissing(self.field.to_string()),
+            ))
+        }
+    }
 
-    #[test]
-    fn parse_action() -> Result<()> {
-        let a: Action = syn::parse_str("skip")?;
-        assert!(a == Action::Skip);
+    fn emit(&self, vis: &Visibility) -> TokenStream {
+        let field = &self.field;
+        self.inner
+            .iter()
+            .map(|(name, ty)| quote!(
+                #vis fn #name(&self) -> &#ty {
+                    &self.#field.#name
+                }
+            ))
+            .collect()
+    }
+}
 
-        let r: Result<Action> = syn::parse_str("skip = blah");
-        assert!(r.is_err());
+/// A `const fn new` taking every field in declaration order, getters skipped or not.
+struct Constructor {
+    name: Ident,
+    fields: Vec<(Ident, Type)>,
+}
 
-        let a: Action = syn::parse_str("rename = \"hello\"")?;
-        let check = Action::Rename(Ident::new("hello", Span::call_site()));
-        assert!(a == check);
+impl Constructor {
+    fn new(name: Ident, fields_named: &FieldsNamed) -> Self {
+        let fields = fields_named.named
+            .iter()
+            .filter_map(|field| field.ident.clone().map(|ident| (ident, field.ty.clone())))
+            .collect();
 
-        let r: Result<Action> = syn::parse_str("rename + \"chooga\"");        
-        assert!(r.is_err());
+        Constructor { name, fields }
+    }
 
-        let r: Result<Action> = syn::parse_str("rename = \"chooga\" | bongle");
-        assert!(r.is_err());
+    fn emit(&self, vis: &Visibility) -> TokenStream {
+        let name = &self.name;
+        let names = self.fields.iter().map(|(name, _)| name);
+        let params = self.fields.iter().map(|(name, ty)| quote!(#name: #ty));
+
+        quote!(
+            #vis const fn #name(#(#params),*) -> Self {
+                Self { #(#names),* }
+            }
+        )
+    }
+}
+
+/// A `snapshot(&self)` cloning every field into a struct of the same field names.
+struct Snapshot {
+    name: Ident,
+    path: Path,
+    fields: Vec<(Ident, Type)>,
+}
+
+impl Snapshot {
+    fn new(name: Ident, path: Path, fields_named: &FieldsNamed) -> Self {
+        let fields = fields_named.named
+            .iter()
+            .filter_map(|field| field.ident.clone().map(|ident| (ident, field.ty.clone())))
+            .collect();
+
+        Snapshot { name, path, fields }
+    }
+
+    /// Each clone is spanned on the field type, so a field that isn't `Clone` points the
+    /// compiler error at that type.
+    fn emit(&self, vis: &Visibility) -> TokenStream {
+        let name = &self.name;
+        let returns = &self.path;
+        // Generic arguments in an expression need a turbofish, eg; `Snapshot::<T> { .. }`.
+        let mut constructor = self.path.clone();
+        for segment in constructor.segments.iter_mut() {
+            if let PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+                arguments.colon2_token = Some(Default::default());
+            }
+        }
+        let clones = self.fields.iter().map(|(field, ty)| {
+            let clone = quote_spanned!(ty.span()=>
+                ::core::clone::Clone::clone(&self.#field)
+            );
+            quote!(#field: #clone)
+        });
+
+        quote!(
+            #vis fn #name(&self) -> #returns {
+                #constructor { #(#clones),* }
+            }
+        )
+    }
+}
+
+/// An iterator over references to every field of a struct whose fields share one type.
+struct IterFields {
+    ty: Type,
+    fields: Vec<Ident>,
+}
+
+impl IterFields {
+    /// Types are compared as written, so `u8` and `core::primitive::u8` differ.
+    fn new(fields_named: &FieldsNamed) -> Result<Self> {
+        let first = fields_named.named
+            .first()
+            .ok_or(Error::new(Span::call_site(), Problem::IterFieldsEmpty))?;
+        let written = |ty: &Type| quote!(#ty).to_string();
+        let expected = written(&first.ty);
+        let mut fields = Vec::new();
+        for field in fields_named.named.iter() {
+            if written(&field.ty) != expected {
+                return Err(Error::new_spanned(
+                    &field.ty,
+                    Problem::IterFieldsMixed(expected),
+                ));
+            }
+            fields.extend(field.ident.clone());
+        }
+
+        Ok(IterFields { ty: first.ty.clone(), fields })
+    }
+
+    /// The array is passed to `IntoIterator::into_iter` so it's iterated by value on any
+    /// edition.
+    fn emit(&self, vis: &Visibility) -> TokenStream {
+        let ty = &self.ty;
+        let fields = self.fields.iter();
+        quote!(
+            #vis fn iter_fields(&self) -> impl ::core::iter::Iterator<Item = &#ty> + '_ {
+                ::core::iter::IntoIterator::into_iter([#(&self.#fields),*])
+            }
+        )
+    }
+}
+
+/// A getter computed from an expression over the struct, eg; `area = "self.w * self.h"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Computed {
+    name: Ident,
+    expr: Box<Expr>,
+    ty: Box<Type>,
+}
+
+impl Parse for Computed {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse::<Ident>()?;
+        let _ = input.parse::<syn::Token![=]>()?;
+        let (expr, ty) = typed_expr(input)?;
+        let _ = input.parse::<Option<syn::Token![,]>>()?;
+        if !input.is_empty() {
+            Err(Error::new(Span::call_site(), Problem::TokensFollowFlag("ty")))
+        } else {
+            Ok(Computed { name, expr, ty })
+        }
+    }
+}
+
+/// Parse an expression string followed by its type, eg; `"self.a.len()", ty = "usize"`.
+fn typed_expr(input: ParseStream) -> Result<(Box<Expr>, Box<Type>)> {
+    syn::custom_keyword!(ty);
+
+    let expr = input.parse::<LitStr>()?.parse::<Expr>()?;
+    let _ = input.parse::<syn::Token![,]>()?;
+    let _ = input.parse::<ty>()?;
+    let _ = input.parse::<syn::Token![=]>()?;
+    let ty = input.parse::<LitStr>()?.parse::<Type>()?;
+
+    Ok((Box::new(expr), Box::new(ty)))
+}
+
+impl Computed {
+    fn emit(&self, vis: &Visibility) -> TokenStream {
+        let Computed { name, expr, ty } = self;
+
+        quote!(
+            #vis fn #name(&self) -> #ty {
+                #expr
+            }
+        )
+    }
+}
+
+/// Item yielded by iterating over a `Vec<T>` or a `HashMap<K, V>`/`BTreeMap<K, V>`.
+fn iter_item(ty: &Type) -> Option<Type> {
+    if let Some(elem) = wrapped(ty, "Vec") {
+        return Some(parse_quote!(&#elem));
+    }
+
+    let (key, value) = map_entry(ty)?;
+    Some(parse_quote!((&#key, &#value)))
+}
+
+/// Getter name taken from the last path segment of a type, eg; `config` for
+/// `crate::Config` or `&'a Config`.
+fn type_name(ty: &Type) -> Option<Ident> {
+    match ty {
+        Type::Reference(tr) => type_name(&tr.elem),
+        Type::Path(tp) if tp.qself.is_none() => {
+            let segment = tp.path.segments.last()?;
+            let name = snake_case(segment.ident.to_string().trim_start_matches("r#"));
+            syn::parse_str::<Ident>(name.as_str())
+                .ok()
+                .map(|ident| Ident::new(&ident.to_string(), segment.ident.span()))
+        },
+        _ => None,
+    }
+}
+
+/// Key and value types of a `HashMap<K, V>`, `HashMap<K, V, S>` or `BTreeMap<K, V>`.
+fn map_entry(ty: &Type) -> Option<(&Type, &Type)> {
+    let arguments = type_arguments(ty, "HashMap")
+        .or_else(|| type_arguments(ty, "BTreeMap"))?;
+    match arguments.as_slice() {
+        [key, value] | [key, value, _] => Some((key, value)),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Mode {
+    Ref,
+    Slice(Box<Type>),
+    CellGet(Box<Type>),
+    TryInto(Box<Type>),
+    AsDyn(Box<Type>),
+    Deref(Box<Type>),
+    Body(Box<Expr>, Box<Type>),
+    Lock(Box<Type>),
+    TryLock(Box<Type>),
+    Ptr,
+    PtrMut,
+    ManuallyDrop(Box<Type>),
+    /// The contained type and the variant that panics, `None` or `Err`.
+    Unwrap(Box<Type>, &'static str),
+    Copy,
+    Clone,
+    AsRef(Box<Type>),
+    ToOwned(Box<Type>),
+    PinRef(Box<Type>),
+    Once(Box<Type>),
+    /// The pointed to type and whether it's upgraded to an `Rc` rather than an `Arc`.
+    Upgrade(Box<Type>, bool),
+}
+
+impl Mode {
+    /// Only one mode can shape a getter.
+    fn choose(mode: &mut Option<Mode>, chosen: Mode, ty: &Type) -> Result<()> {
+        if let Some(previous) = mode {
+            return Err(Error::new_spanned(
+                ty,
+                Problem::ConflictingOptions(previous.option(), chosen.option()),
+            ));
+        }
+        *mode = Some(chosen);
+
+        Ok(())
+    }
+
+    /// Name of the attribute option selecting this mode.
+    fn option(&self) -> &'static str {
+        match self {
+            Mode::Ref => "ref",
+            Mode::Slice(_) => "as_slice",
+            Mode::CellGet(_) => "cell_get",
+            Mode::TryInto(_) => "try_into",
+            Mode::AsDyn(_) => "as_dyn",
+            Mode::Deref(_) => "deref",
+            Mode::Body(_, _) => "body",
+            Mode::Lock(_) | Mode::TryLock(_) => "lock",
+            Mode::Ptr => "ptr",
+            Mode::PtrMut => "ptr_mut",
+            Mode::ManuallyDrop(_) => "manually_drop",
+            Mode::Unwrap(_, _) => "unwrap",
+            Mode::Copy => "copy",
+            Mode::Clone => "clone",
+            Mode::AsRef(_) => "as_ref",
+            Mode::ToOwned(_) => "to_owned",
+            Mode::PinRef(_) => "pin_ref",
+            Mode::Once(_) => "once",
+            Mode::Upgrade(_, _) => "upgrade",
+        }
+    }
+
+    /// The mode an option selects for a field of type `ty`, checking it suits the type.
+    fn from_action(action: Action, ty: &Type) -> Result<Mode> {
+        let mode = match action {
+            Action::Ref => Mode::Ref,
+            Action::AsSlice => {
+                let elem = slice_element(ty)
+                    .ok_or(Error::new_spanned(ty, Problem::NotSliceable))?;
+                Mode::Slice(Box::new(elem.clone()))
+            },
+            Action::CellGet => {
+                let inner = wrapped(ty, "Cell")
+                    .ok_or(Error::new_spanned(ty, Problem::NotACell))?;
+                Mode::CellGet(Box::new(inner.clone()))
+            },
+            Action::TryInto(target) => Mode::TryInto(target),
+            Action::AsDyn(object) => Mode::AsDyn(object),
+            Action::Deref(target) => Mode::Deref(target),
+            Action::Body(expr, ty) => Mode::Body(expr, ty),
+            Action::Lock { try_lock } => {
+                let inner = Box::new(wrapped(ty, "Mutex")
+                    .ok_or(Error::new_spanned(ty, Problem::NotAMutex))?
+                    .clone());
+                if try_lock { Mode::TryLock(inner) } else { Mode::Lock(inner) }
+            },
+            Action::Ptr { mutable } => if mutable { Mode::PtrMut } else { Mode::Ptr },
+            Action::ManuallyDrop => {
+                let inner = wrapped(ty, "ManuallyDrop")
+                    .ok_or(Error::new_spanned(ty, Problem::NotManuallyDrop))?;
+                Mode::ManuallyDrop(Box::new(inner.clone()))
+            },
+            Action::Unwrap => {
+                let option = wrapped(ty, "Option");
+                let result = type_arguments(ty, "Result");
+                match (option, result.as_deref()) {
+                    (Some(inner), _) => Mode::Unwrap(Box::new(inner.clone()), "None"),
+                    (None, Some([ok, _])) => Mode::Unwrap(Box::new((*ok).clone()), "Err"),
+                    _ => return Err(Error::new_spanned(ty, Problem::NotUnwrappable)),
+                }
+            },
+            Action::Copy => Mode::Copy,
+            Action::Clone => Mode::Clone,
+            Action::AsRef => {
+                let inner = wrapped(ty, "Option")
+                    .ok_or(Error::new_spanned(ty, Problem::NotAnOption))?;
+                Mode::AsRef(Box::new(inner.clone()))
+            },
+            Action::ToOwned => {
+                let owned = owned_target(ty)
+                    .ok_or(Error::new_spanned(ty, Problem::NotToOwned))?;
+                Mode::ToOwned(Box::new(owned))
+            },
+            Action::PinRef => {
+                let inner = wrapped(ty, "Pin")
+                    .and_then(|boxed| wrapped(boxed, "Box"))
+                    .ok_or(Error::new_spanned(ty, Problem::NotAPinnedBox))?;
+                Mode::PinRef(Box::new(inner.clone()))
+            },
+            Action::Once => {
+                let inner = wrapped(ty, "OnceCell")
+                    .or_else(|| wrapped(ty, "OnceLock"))
+                    .ok_or(Error::new_spanned(ty, Problem::NotAOnceCell))?;
+                Mode::Once(Box::new(inner.clone()))
+            },
+            Action::Upgrade { rc } => {
+                let inner = wrapped(ty, "Weak")
+                    .ok_or(Error::new_spanned(ty, Problem::NotAWeak))?;
+                // A bare `Weak<T>` is taken to be `std::sync::Weak`.
+                let rc = rc.unwrap_or_else(|| match ty {
+                    Type::Path(tp) => tp.path.segments.iter().any(|segment| segment.ident == "rc"),
+                    _ => false,
+                });
+                Mode::Upgrade(Box::new(inner.clone()), rc)
+            },
+            Action::Skip(_)
+            | Action::Rename(_)
+            | Action::Alias(_)
+            | Action::Iter
+            | Action::Indexed
+            | Action::Eq
+            | Action::Replace
+            | Action::Take
+            | Action::Consume
+            | Action::Hash
+            | Action::GatLifetime
+            | Action::ArrayFull
+            | Action::ResultSplit
+            | Action::Map(_, _)
+            | Action::DocAlias(_)
+            | Action::Keep
+            | Action::MapAccess
+            | Action::RenameFromType
+            | Action::ConstLen
+            | Action::Order(_)
+            | Action::Bound(_) => {
+                return Err(Error::new(Span::call_site(), Problem::InvalidAttribute));
+            },
+        };
+
+        Ok(mode)
+    }
+}
+
+pub struct Field {
+    ty: Type,    
+    name: Ident,
+    /// How the field is reached from `self`, `self.name` or `self.0` for a tuple struct.
+    member: Member,
+    /// The variant holding the field, for a single-variant enum.
+    variant: Option<Ident>,
+    getter: Ident,
+    aliases: Vec<Ident>,
+    mode: Mode,
+    iter: Option<Box<Type>>,
+    indexed: Option<Box<Type>>,
+    eq: bool,
+    replace: bool,
+    consume: bool,
+    /// Whether to generate `take_<getter>`, and if so whether the field is an `Option`.
+    take: Option<bool>,
+    hash: bool,
+    /// Whether the borrow of `self` is named, with a `where Self: 'getter` bound.
+    gat_lifetime: bool,
+    /// The element type and length of an array given `array_full`, one element getter
+    /// for each index.
+    elements: Option<(Box<Type>, usize)>,
+    /// The `Ok` and `Err` types of a `Result` field given `result_split`.
+    result_split: Option<(Box<Type>, Box<Type>)>,
+    map: Option<(Ident, Box<Type>)>,
+    doc_aliases: Vec<String>,
+    map_access: Option<(Box<Type>, Box<Type>)>,
+    const_len: Option<Box<Expr>>,
+    bounds: Vec<WherePredicate>,
+    /// Whether the getter takes `self` by value, only for a `Copy` struct.
+    by_value: bool,
+    order: Option<LitInt>,
+    /// The field's `#[deprecated]` attribute, carried over to its getters.
+    deprecated: Option<Attribute>,
+}
+
+impl Field {
+    /// Fields without an option choosing the getter's mode take it from `default`.
+    fn from_field(field: &syn::Field, default: Option<&Action>) -> Result<Option<Self>> {
+        let name: Ident =  field.ident
+            .clone()
+            .ok_or(Error::new(Span::call_site(), Problem::UnnamedField))?;
+
+        // Most fields carry no attributes at all.
+        let actions = if field.attrs.is_empty() {
+            Vec::new()
+        } else {
+            get_actions_from(field.attrs.as_slice())?
+        };
+        
+        // A getter for a `()` field returns nothing of use, so one is only made if asked.
+        let unit = matches!(&field.ty, Type::Tuple(tuple) if tuple.elems.is_empty());
+        let mut skip = unit;
+        let mut getter = name.clone();
+        let mut renamed = false;
+        let mut aliases = Vec::new();
+        let mut mode: Option<Mode> = None;
+        let mut iter = None;
+        let mut indexed = None;
+        let mut eq = false;
+        let mut replace = false;
+        let mut consume = false;
+        let mut take = None;
+        let mut hash = false;
+        let mut gat_lifetime = false;
+        let mut elements = None;
+        let mut result_split = None;
+        let mut map = None;
+        let mut doc_aliases = Vec::new();
+        let mut map_access = None;
+        let mut const_len = None;
+        let mut bounds = Vec::new();
+        let mut order = None;
+
+        for action in actions {
+            match action {
+                Action::Skip(_) => skip = true,
+                // The first rename names the getter, any later one adds an alias.
+                Action::Rename(ident) if renamed => aliases.push(ident),
+                Action::Rename(ident) => {
+                    skip = false;
+                    renamed = true;
+                    getter = ident;
+                },
+                Action::RenameFromType => {
+                    skip = false;
+                    renamed = true;
+                    getter = type_name(&field.ty)
+                        .ok_or(Error::new_spanned(&field.ty, Problem::NoTypeName))?;
+                },
+                Action::Alias(ident) => aliases.push(ident),
+                Action::Bound(predicates) => bounds.extend(predicates),
+                Action::Order(position) => order = Some(position),
+                Action::Iter => {
+                    let item = iter_item(&field.ty)
+                        .ok_or(Error::new_spanned(&field.ty, Problem::NotIterable))?;
+                    iter = Some(Box::new(item));
+                },
+                Action::Eq => eq = true,
+                Action::Replace => replace = true,
+                Action::Consume => consume = true,
+                Action::Take => take = Some(wrapped(&field.ty, "Option").is_some()),
+                Action::Hash => hash = true,
+                Action::GatLifetime => gat_lifetime = true,
+                Action::ResultSplit => match type_arguments(&field.ty, "Result").as_deref() {
+                    Some([ok, err]) => {
+                        result_split = Some((Box::new((*ok).clone()), Box::new((*err).clone())));
+                    },
+                    _ => return Err(Error::new_spanned(&field.ty, Problem::NotAResult)),
+                },
+                // The length must be a literal to know how many element getters to generate.
+                Action::ArrayFull => {
+                    let (elem, len) = match &field.ty {
+                        Type::Array(ta) => match &ta.len {
+                            Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(len), .. }) => {
+                                (ta.elem.as_ref(), len.base10_parse::<usize>()?)
+                            },
+                            len => return Err(Error::new_spanned(len, Problem::ArrayLenNotLiteral)),
+                        },
+                        ty => return Err(Error::new_spanned(ty, Problem::NotAnArray("array_full"))),
+                    };
+                    elements = Some((Box::new(elem.clone()), len));
+                    Mode::choose(&mut mode, Mode::Slice(Box::new(elem.clone())), &field.ty)?;
+                },
+                Action::Indexed => {
+                    let elem = indexed_element(&field.ty)
+                        .ok_or(Error::new_spanned(&field.ty, Problem::NotIndexable))?;
+                    indexed = Some(Box::new(elem.clone()));
+                },
+                Action::Map(method, ty) => map = Some((method, ty)),
+                Action::DocAlias(alias) => doc_aliases.push(alias),
+                Action::Keep => skip = false,
+                Action::ConstLen => match &field.ty {
+                    Type::Array(ta) => const_len = Some(Box::new(ta.len.clone())),
+                    ty => return Err(Error::new_spanned(ty, Problem::NotAnArray("const_len"))),
+                },
+                Action::MapAccess => {
+                    let (key, value) = map_entry(&field.ty)
+                        .ok_or(Error::new_spanned(&field.ty, Problem::NotAMap))?;
+                    map_access = Some((Box::new(key.clone()), Box::new(value.clone())));
+                },
+                action => {
+                    let chosen = Mode::from_action(action, &field.ty)?;
+                    Mode::choose(&mut mode, chosen, &field.ty)?;
+                },
+            }
+        }
+
+        if skip {
+            return Ok(None);
+        }
+
+        let mode = match (mode, default) {
+            (Some(mode), _) => mode,
+            (None, Some(default)) => Mode::from_action(default.clone(), &field.ty)?,
+            (None, None) => Mode::Ref,
+        };
+
+        Ok(Some(Field {
+            ty: field.ty.clone(),
+            member: Member::Named(name.clone()),
+            variant: None,
+            name,
+            getter,
+            aliases,
+            mode,
+            iter,
+            indexed,
+            eq,
+            replace,
+            consume,
+            take,
+            hash,
+            gat_lifetime,
+            elements,
+            result_split,
+            map,
+            doc_aliases,
+            map_access,
+            const_len,
+            bounds,
+            by_value: false,
+            order,
+            deprecated: field.attrs
+                .iter()
+                .find(|attr| attr.path().is_ident("deprecated"))
+                .cloned(),
+        }))
+    }
+    
+    /// Applies the struct level `strip_prefix`, `rename_all` and `prefix` options, in that
+    /// order. Renamed getters keep the name they were given.
+    fn restyle(
+        &mut self,
+        strip: Option<&str>,
+        prefix: Option<&str>,
+        renamer: Option<&Renamer>,
+    ) -> Result<()> {
+        if self.getter != self.name || (strip.is_none() && prefix.is_none() && renamer.is_none()) {
+            return Ok(());
+        }
+
+        let name = self.name.to_string();
+        let name = name.trim_start_matches("r#");
+        let name = strip
+            .and_then(|strip| name.strip_prefix(strip))
+            .unwrap_or(name);
+        let styled = renamer
+            .map(|renamer| renamer.apply(name))
+            .unwrap_or_else(|| name.to_string());
+        let styled = format!("{}{}", prefix.unwrap_or(""), styled);
+        // Keywords such as `type` are emitted as raw identifiers.
+        self.getter = if syn::parse_str::<Ident>(styled.as_str()).is_ok() {
+            Ident::new(styled.as_str(), self.name.span())
+        } else if syn::parse_str::<Ident>(format!("r#{}", styled).as_str()).is_ok() {
+            Ident::new_raw(styled.as_str(), self.name.span())
+        } else {
+            return Err(Error::new(self.name.span(), Problem::InvalidGetterName(styled)));
+        };
+
+        Ok(())
+    }
+
+    /// For a tuple struct the fields are named by `positional_names` but reached by index.
+    fn from_fields_named(
+        fields_named: &FieldsNamed,
+        default: Option<&Action>,
+        tuple: bool,
+    ) -> Result<Vec<Self>> {
+        fields_named.named
+            .iter()
+            .enumerate()
+            .try_fold(Vec::new(), |mut fields, (index, field)| {
+                if let Some(mut field) = Field::from_field(field, default)? {
+                    if tuple {
+                        field.member = Member::Unnamed(Index::from(index));
+                    }
+                    fields.push(field);
+                }
+
+                Ok(fields)
+            })
+    }
+
+    /// Getters given an `order` come first, sorted by it, then the rest in declaration
+    /// order.
+    fn ordered(fields: Vec<Self>) -> Result<Vec<Self>> {
+        let mut seen: Vec<usize> = Vec::new();
+        let mut keyed = Vec::new();
+        for field in fields {
+            let key = match &field.order {
+                Some(order) => {
+                    let value = order.base10_parse::<usize>()?;
+                    if seen.contains(&value) {
+                        return Err(Error::new(order.span(), Problem::DuplicateOrder(value)));
+                    }
+                    seen.push(value);
+                    Some(value)
+                },
+                None => None,
+            };
+            keyed.push((key, field));
+        }
+        // The sort is stable, so unordered getters stay as declared.
+        keyed.sort_by_key(|(key, _)| (key.is_none(), *key));
+
+        Ok(keyed.into_iter().map(|(_, field)| field).collect())
+    }
+
+    /// Fields skipped with a reason, eg; `#[getter(skip = "internal cache")]`. As in
+    /// `from_field` a later `rename` undoes an earlier `skip`.
+    fn skip_reasons(fields_named: &FieldsNamed) -> Result<Vec<(Ident, String)>> {
+        let mut reasons = Vec::new();
+        for field in fields_named.named.iter() {
+            let mut reason = None;
+            for action in get_actions_from(field.attrs.as_slice())? {
+                match action {
+                    Action::Skip(why) => reason = why,
+                    Action::Rename(_) => reason = None,
+                    _ => (),
+                }
+            }
+            if let (Some(name), Some(reason)) = (&field.ident, reason) {
+                reasons.push((name.clone(), reason));
+            }
+        }
+
+        Ok(reasons)
+    }
+
+    /// Any `doc` attributes are attached to the getter, not the aliases, iterator or mapped
+    /// getter.
+    fn emit(&self, doc: TokenStream, vis: &Visibility, lifetime: &Lifetime) -> TokenStream {
+        let getter = self.emit_getter(vis, lifetime);
+        let deprecated = &self.deprecated;
+        let place = self.place(Span::call_site());
+
+        let aliases = self.aliases.iter().map(|alias| {
+            let getter_name = &self.getter;
+            let (receiver, returns, _) = self.signature(None);
+            let copy_self = self.copy_self();
+            let note = format!(" Alias of [`Self::{}`].", getter_name);
+            quote!(
+                #[doc = #note]
+                #deprecated #vis fn #alias(#receiver self) -> #returns #copy_self {
+                    self.#getter_name()
+                }
+            )
+        });
+
+        let iter = self.iter.as_ref().map(|item| {
+            let iter_name = format_ident!("{}_iter", self.getter);
+            quote!(
+                #deprecated #vis fn #iter_name(&self) -> impl Iterator<Item = #item> + '_ {
+                    #place.iter()
+                }
+            )
+        });
+
+        let indexed = self.indexed.as_ref().map(|elem| {
+            let at_name = format_ident!("{}_at", self.getter);
+            quote!(
+                #deprecated #vis fn #at_name(&self, index: usize) -> ::core::option::Option<&#elem> {
+                    #place.get(index)
+                }
+            )
+        });
+
+        // Generic over what it's compared with, so a field that isn't `PartialEq` only leaves
+        // a helper that can't be called.
+        let eq = if self.eq {
+            let eq_name = format_ident!("{}_eq", self.getter);
+            let ty = &self.ty;
+            Some(quote!(
+                #deprecated #vis fn #eq_name<Q: ?::core::marker::Sized>(&self, other: &Q) -> bool
+                where
+                    #ty: ::core::cmp::PartialEq<Q>
+                {
+                    ::core::cmp::PartialEq::eq(&#place, other)
+                }
+            ))
+        } else {
+            None
+        };
+
+        let replace = if self.replace {
+            let replace_name = format_ident!("replace_{}", self.getter);
+            let ty = &self.ty;
+            Some(quote!(
+                #deprecated #vis fn #replace_name(&mut self, value: #ty) -> #ty {
+                    ::core::mem::replace(&mut #place, value)
+                }
+            ))
+        } else {
+            None
+        };
+
+        // An `Option` is left `None`, anything else its `Default`.
+        let take = self.take.map(|option| {
+            let take_name = format_ident!("take_{}", self.getter);
+            let ty = &self.ty;
+            let body = if option {
+                quote!(#place.take())
+            } else {
+                quote!(::core::mem::take(&mut #place))
+            };
+            quote!(
+                #deprecated #vis fn #take_name(&mut self) -> #ty {
+                    #body
+                }
+            )
+        });
+
+        // Moving the field out drops the rest of the struct, so it can't implement `Drop`.
+        let consume = if self.consume {
+            let into_name = format_ident!("into_{}", self.getter);
+            let ty = &self.ty;
+            let member = &self.member;
+            let body = self
+                .matched(Span::call_site())
+                .unwrap_or_else(|| quote!(self.#member));
+            Some(quote!(
+                #deprecated #vis fn #into_name(self) -> #ty {
+                    #body
+                }
+            ))
+        } else {
+            None
+        };
+
+        // Spanned on the field type, so a field that isn't `Hash` is pointed at. A fresh
+        // `DefaultHasher` is deterministic within a build, not across Rust releases.
+        let hash = if self.hash {
+            let hash_name = format_ident!("{}_hash", self.getter);
+            let span = self.ty.span();
+            let place = self.place(span);
+            let hashed = quote_spanned!(span=>
+                ::core::hash::Hash::hash(&#place, &mut hasher);
+            );
+            let std = std_at(span);
+            Some(quote!(
+                #deprecated #vis fn #hash_name(&self) -> u64 {
+                    let mut hasher = #std::collections::hash_map::DefaultHasher::new();
+                    #hashed
+                    ::core::hash::Hasher::finish(&hasher)
+                }
+            ))
+        } else {
+            None
+        };
+
+        let elements = self.elements.as_ref().map(|(elem, len)| {
+            (0..*len)
+                .map(|index| {
+                    let element_name = format_ident!("{}_{}", self.getter, index);
+                    let index = Literal::usize_unsuffixed(index);
+                    quote!(
+                        #deprecated #vis fn #element_name(&self) -> &#elem {
+                            &#place[#index]
+                        }
+                    )
+                })
+                .collect::<TokenStream>()
+        });
+
+        let result_split = self.result_split.as_ref().map(|(ok, err)| {
+            let ok_name = format_ident!("{}_ok", self.getter);
+            let err_name = format_ident!("{}_err", self.getter);
+            quote!(
+                #deprecated #vis fn #ok_name(&self) -> ::core::option::Option<&#ok> {
+                    #place.as_ref().ok()
+                }
+
+                #deprecated #vis fn #err_name(&self) -> ::core::option::Option<&#err> {
+                    #place.as_ref().err()
+                }
+            )
+        });
+
+        let map = self.map.as_ref().map(|(method, ty)| {
+            let mapped_name = format_ident!("{}_mapped", self.getter);
+            quote!(
+                #deprecated #vis fn #mapped_name(&self) -> #ty {
+                    #place.#method()
+                }
+            )
+        });
+
+        let map_access = self.map_access.as_ref().map(|(key, value)| {
+            let get_name = format_ident!("{}_get", self.getter);
+            let contains_name = format_ident!("{}_contains", self.getter);
+            quote!(
+                #deprecated #vis fn #get_name(&self, key: &#key) -> ::core::option::Option<&#value> {
+                    #place.get(key)
+                }
+
+                #deprecated #vis fn #contains_name(&self, key: &#key) -> bool {
+                    #place.contains_key(key)
+                }
+            )
+        });
+
+        let const_len = self.const_len.as_ref().map(|len| {
+            let len_name = format_ident!("{}_len", self.getter);
+            quote!(
+                #deprecated #vis const fn #len_name() -> usize {
+                    #len
+                }
+            )
+        });
+
+        quote!(
+            #doc
+            #getter
+            #(#aliases)*
+            #iter
+            #indexed
+            #eq
+            #replace
+            #take
+            #consume
+            #hash
+            #elements
+            #result_split
+            #map
+            #map_access
+            #const_len
+        )
+    }
+
+    /// With `gat_lifetime` the borrow of `self` is named `lifetime`.
+    fn emit_getter(&self, vis: &Visibility, lifetime: &Lifetime) -> TokenStream {
+        let getter_name = &self.getter;
+        let deprecated = &self.deprecated;
+        // A getter taking `self` by value has no borrow to name.
+        let lifetime = if self.gat_lifetime && !self.by_value {
+            Some(lifetime.clone())
+        } else {
+            None
+        };
+        let (receiver, returns, body) = self.signature(lifetime.as_ref());
+        let panics = match &self.mode {
+            Mode::Unwrap(_, variant) => {
+                let when = format!(" If the field is `{}`.", variant);
+                quote!(
+                    #[doc = " # Panics"]
+                    #[doc = ""]
+                    #[doc = #when]
+                )
+            },
+            _ => TokenStream::new(),
+        };
+        let aliases = self.doc_aliases.iter();
+        let (generic, bound) = match &lifetime {
+            Some(lifetime) => (quote!(<#lifetime>), quote!(where Self: #lifetime)),
+            None => (TokenStream::new(), self.copy_self()),
+        };
+
+        quote!(
+            #panics
+            #(#[doc(alias = #aliases)])*
+            #deprecated #vis fn #getter_name #generic (#receiver self) -> #returns #bound {
+                #body
+            }
+        )
+    }
+
+    /// The field as a place expression, `self.name`, or for a single-variant enum matched
+    /// out of its variant, `(*match self { Self::Variant { name, .. } => name })`. Spanned
+    /// at `span`, save the field itself.
+    fn place(&self, span: Span) -> TokenStream {
+        let member = &self.member;
+        match self.matched(span) {
+            Some(matched) => quote_spanned!(span=> (*#matched)),
+            None => quote_spanned!(span=> self.#member),
+        }
+    }
+
+    /// For a single-variant enum, the field matched out of `self`. A reference to it when
+    /// `self` is borrowed, the field itself when `self` is taken by value.
+    fn matched(&self, span: Span) -> Option<TokenStream> {
+        let member = &self.member;
+        let name = &self.name;
+        self.variant.as_ref().map(|variant| {
+            // Named fields bind in shorthand, positional ones by their index.
+            let binding = match member {
+                Member::Named(_) => quote!(#name),
+                Member::Unnamed(_) => quote!(#member: #name),
+            };
+            quote_spanned!(span=> match self { Self::#variant { #binding, .. } => #name })
+        })
+    }
+
+    /// A getter taking `self` by value needs the struct to be `Copy`, otherwise calling it
+    /// would consume the struct.
+    fn copy_self(&self) -> TokenStream {
+        if self.by_value {
+            quote!(where Self: ::core::marker::Copy)
+        } else {
+            TokenStream::new()
+        }
+    }
+
+    /// Whether `by_value_self` applies, to getters returning the field or a copy of it.
+    fn takes_by_value(&self) -> bool {
+        matches!(self.mode, Mode::Ref | Mode::Copy)
+    }
+
+    /// How `self` is borrowed, eg; `&` or `&mut`, the return type and the body of the
+    /// getter. Borrows of `self` are elided unless given a `lifetime`.
+    fn signature(
+        &self,
+        lifetime: Option<&Lifetime>,
+    ) -> (TokenStream, TokenStream, TokenStream) {
+        let returns = &self.ty;
+        let place = self.place(Span::call_site());
+        let borrow = quote!(&#lifetime);
+        let std = std_at(self.ty.span());
+        let anonymous = match lifetime {
+            Some(lifetime) => quote!(#lifetime),
+            None => quote!('_),
+        };
+        if self.by_value {
+            return (TokenStream::new(), quote!(#returns), quote!(#place));
+        }
+
+        match &self.mode {
+            Mode::Slice(elem) => (
+                quote!(#borrow),
+                quote!(#borrow [#elem]),
+                quote!(&#place),
+            ),
+            Mode::CellGet(inner) => (
+                quote!(#borrow),
+                quote!(#inner),
+                quote!(#place.get()),
+            ),
+            Mode::TryInto(target) => (
+                quote!(#borrow),
+                quote!(::core::result::Result<
+                    #target,
+                    <#target as ::core::convert::TryFrom<#returns>>::Error,
+                >),
+                quote!(<#target as ::core::convert::TryFrom<#returns>>::try_from(
+                    ::core::clone::Clone::clone(&#place)
+                )),
+            ),
+            // `&dyn A + Send` doesn't parse, so more than one bound needs parentheses.
+            Mode::AsDyn(object) => match object.as_ref() {
+                Type::TraitObject(to) if to.bounds.len() > 1 => (
+                    quote!(#borrow),
+                    quote!(#borrow (#object)),
+                    quote!(&#place),
+                ),
+                _ => (quote!(#borrow), quote!(#borrow #object), quote!(&#place)),
+            },
+            Mode::Deref(target) => (
+                quote!(#borrow),
+                quote!(#borrow #target),
+                quote!(&*#place),
+            ),
+            Mode::Body(expr, ty) => (quote!(#borrow), quote!(#ty), quote!(#expr)),
+            Mode::Lock(inner) => (
+                quote!(#borrow),
+                quote!(#std::sync::MutexGuard<#anonymous, #inner>),
+                quote!(#place.lock().unwrap()),
+            ),
+            Mode::TryLock(inner) => (
+                quote!(#borrow),
+                quote!(#std::sync::TryLockResult<#std::sync::MutexGuard<#anonymous, #inner>>),
+                quote!(#place.try_lock()),
+            ),
+            Mode::Ptr => (
+                quote!(#borrow),
+                quote!(*const #returns),
+                quote!(&#place as *const #returns),
+            ),
+            Mode::PtrMut => (
+                quote!(#borrow mut),
+                quote!(*mut #returns),
+                quote!(&mut #place as *mut #returns),
+            ),
+            Mode::ManuallyDrop(inner) => (
+                quote!(#borrow),
+                quote!(#borrow #inner),
+                quote!(&*#place),
+            ),
+            Mode::Unwrap(inner, _) => (
+                quote!(#borrow),
+                quote!(#borrow #inner),
+                quote!(#place.as_ref().unwrap()),
+            ),
+            Mode::Copy => (quote!(#borrow), quote!(#returns), quote!(#place)),
+            Mode::Clone => (
+                quote!(#borrow),
+                quote!(#returns),
+                quote!(::core::clone::Clone::clone(&#place)),
+            ),
+            Mode::AsRef(inner) => (
+                quote!(#borrow),
+                quote!(::core::option::Option<#borrow #inner>),
+                quote!(#place.as_ref()),
+            ),
+            Mode::ToOwned(owned) => (
+                quote!(#borrow),
+                quote!(#owned),
+                quote!(#std::borrow::ToOwned::to_owned(#place)),
+            ),
+            Mode::PinRef(inner) => (
+                quote!(#borrow),
+                quote!(#borrow #inner),
+                quote!(#place.as_ref().get_ref()),
+            ),
+            Mode::Once(inner) => (
+                quote!(#borrow),
+                quote!(::core::option::Option<#borrow #inner>),
+                quote!(#place.get()),
+            ),
+            Mode::Upgrade(inner, true) => (
+                quote!(#borrow),
+                quote!(::core::option::Option<#std::rc::Rc<#inner>>),
+                quote!(#place.upgrade()),
+            ),
+            Mode::Upgrade(inner, false) => (
+                quote!(#borrow),
+                quote!(::core::option::Option<#std::sync::Arc<#inner>>),
+                quote!(#place.upgrade()),
+            ),
+            Mode::Ref => match &self.ty {
+                // The reference is returned as it is, however `self` is borrowed.
+                Type::Reference(_) if lifetime.is_some() && self.gat_lifetime => {
+                    (quote!(#borrow), quote!(#returns), quote!(#place))
+                },
+                Type::Reference(tr) => {
+                    let lifetime = tr.lifetime.as_ref();
+                    (quote!(&#lifetime), quote!(#returns), quote!(#place))
+                },
+                _ => (quote!(#borrow), quote!(#borrow #returns), quote!(&#place)),
+            },
+        }
+    }
+}
+
+pub struct NamedStruct<'a> {
+    original: &'a DeriveInput,
+    name: Ident,
+    fields: Vec<Field>,
+    constructor: Option<Constructor>,
+    default_fn: Option<Ident>,
+    snapshot: Option<Snapshot>,
+    groups: Vec<Group>,
+    flattened: Vec<Flatten>,
+    computed: Vec<Computed>,
+    keep_empty_impl: bool,
+    doc_examples: bool,
+    skip_reasons: Vec<(Ident, String)>,
+    cfgs: Vec<Meta>,
+    free_fn: bool,
+    override_where: Option<WhereClause>,
+    vis: Visibility,
+    non_snake_case: bool,
+    /// Any field is `#[deprecated]`, so the generated items allow its use.
+    deprecated: bool,
+    field_names: Option<Vec<String>>,
+    iter_fields: Option<IterFields>,
+    /// Every field's `bound`, with `merge_bounds`, added to the one impl block.
+    merged_bounds: Vec<WherePredicate>,
+    /// The borrow of `self` named by `gat_lifetime` and free functions, `'getter` unless
+    /// the struct has a lifetime of that name.
+    lifetime: Lifetime,
+}
+
+impl<'a> NamedStruct<'a> {
+    pub fn emit(&self) -> TokenStream {
+        let (impl_generics, struct_generics, _) = self.original.generics
+            .split_for_impl();        
+        let where_clause = self.where_with(self.merged_bounds.as_slice());
+        let struct_name = &self.name;
+        let methods: Vec<TokenStream> = self.constructor
+            .iter()
+            .map(|constructor| constructor.emit(&self.vis))
+            .chain(self.default_fn.iter().map(|name| {
+                let vis = &self.vis;
+                quote!(
+                    #vis fn #name() -> Self where Self: ::core::default::Default {
+                        <Self as ::core::default::Default>::default()
+                    }
+                )
+            }))
+            .chain(
+                self.fields
+                    .iter()
+                    .filter(|field| field.bounds.is_empty())
+                    .map(|field| field.emit(self.doc_example(field), &self.vis, &self.lifetime))
+            )
+            .chain(self.snapshot.iter().map(|snapshot| snapshot.emit(&self.vis)))
+            .chain(self.iter_fields.iter().map(|iter_fields| iter_fields.emit(&self.vis)))
+            .chain(self.field_names.iter().map(|names| {
+                let vis = &self.vis;
+                quote!(
+                    #vis const fn field_names() -> &'static [&'static str] {
+                        &[#(#names),*]
+                    }
+                )
+            }))
+            .chain(self.groups.iter().map(|group| group.emit(&self.vis)))
+            .chain(self.flattened.iter().map(|flattened| flattened.emit(&self.vis)))
+            .chain(self.computed.iter().map(|computed| computed.emit(&self.vis)))
+            .collect();
+
+        let bounded = self.emit_bounded();
+        if methods.is_empty() && (bounded.is_empty() && !self.keep_empty_impl) {
+            return TokenStream::new();
+        }
+
+        let skipped = self.emit_skip_reasons();
+        let cfgs = self.cfgs.iter();
+        let free_fns = self.emit_free_fns();
+        let allow = self.emit_allow();
+
+        quote!(
+            #(#[cfg(#cfgs)])*
+            #skipped
+            #allow
+            impl #impl_generics #struct_name #struct_generics
+                #where_clause
+            {
+                #(#methods)*
+            }
+
+            #bounded
+
+            #free_fns
+        )        
+    }
+
+    /// The struct's where clause, or `override_where`, with a field's `bound` added. A
+    /// predicate already in the clause isn't repeated.
+    fn where_with(&self, bounds: &[WherePredicate]) -> Option<WhereClause> {
+        let base = self.override_where
+            .as_ref()
+            .or(self.original.generics.where_clause.as_ref());
+        if bounds.is_empty() {
+            return base.cloned();
+        }
+
+        let mut clause = base.cloned().unwrap_or_else(|| WhereClause {
+            where_token: Default::default(),
+            predicates: Punctuated::new(),
+        });
+        for bound in bounds {
+            let written = quote!(#bound).to_string();
+            if !clause.predicates.iter().any(|existing| quote!(#existing).to_string() == written) {
+                clause.predicates.push(bound.clone());
+            }
+        }
+
+        Some(clause)
+    }
+
+    /// Getters of fields given a `bound`, one impl block for each distinct set of bounds in
+    /// the order they first appear.
+    fn emit_bounded(&self) -> TokenStream {
+        let mut blocks: Vec<(String, &[WherePredicate], Vec<&Field>)> = Vec::new();
+        for field in self.fields.iter().filter(|field| !field.bounds.is_empty()) {
+            let bounds = field.bounds.as_slice();
+            let key = quote!(#(#bounds),*).to_string();
+            match blocks.iter_mut().find(|(existing, _, _)| *existing == key) {
+                Some((_, _, fields)) => fields.push(field),
+                None => blocks.push((key, bounds, vec![field])),
+            }
+        }
+
+        let (impl_generics, struct_generics, _) = self.original.generics.split_for_impl();
+        let struct_name = &self.name;
+        blocks
+            .into_iter()
+            .map(|(_, bounds, fields)| {
+                let where_clause = self.where_with(bounds);
+                let methods = fields
+                    .into_iter()
+                    .map(|field| field.emit(self.doc_example(field), &self.vis, &self.lifetime));
+                let cfgs = self.cfgs.iter();
+                let allow = self.emit_allow();
+                quote!(
+                    #(#[cfg(#cfgs)])*
+                    #allow
+                    impl #impl_generics #struct_name #struct_generics
+                        #where_clause
+                    {
+                        #(#methods)*
+                    }
+                )
+            })
+            .collect()
+    }
+
+    /// Free functions `<struct>_<getter>(s: &Struct)` calling each field getter.
+    fn emit_free_fns(&self) -> TokenStream {
+        if !self.free_fn {
+            return TokenStream::new();
+        }
+
+        // Without `self` the borrow of the struct can't be elided, so it's named.
+        let lifetime = self.lifetime.clone();
+        let mut generics = self.original.generics.clone();
+        generics.params.insert(0, GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+        let (impl_generics, _, _) = generics.split_for_impl();
+        let (_, struct_generics, _) = self.original.generics.split_for_impl();
+        let struct_name = &self.name;
+        let prefix = snake_case(struct_name.to_string().as_str());
+
+        self.fields
+            .iter()
+            .map(|field| {
+                let getter_name = &field.getter;
+                let free_name = format_ident!("{}_{}", prefix, getter_name);
+                let (receiver, returns, _) = field.signature(Some(&lifetime));
+                let mut bounds = self.merged_bounds.clone();
+                bounds.extend(field.bounds.iter().cloned());
+                if field.by_value {
+                    bounds.push(parse_quote!(
+                        #struct_name #struct_generics: ::core::marker::Copy
+                    ));
+                }
+                let where_clause = self.where_with(bounds.as_slice());
+                let cfgs = self.cfgs.iter();
+                let vis = &self.vis;
+                let allow = self.emit_allow();
+                quote!(
+                    #(#[cfg(#cfgs)])*
+                    #allow
+                    #vis fn #free_name #impl_generics (
+                        s: #receiver #struct_name #struct_generics
+                    ) -> #returns
+                        #where_clause
+                    {
+                        <#struct_name #struct_generics>::#getter_name(s)
+                    }
+                )
+            })
+            .collect()
+    }
+
+    /// Getters renamed to a case other than snake case would otherwise trip the lint, as
+    /// would `new`, `snapshot`, aliases and free functions touching a deprecated field.
+    fn emit_allow(&self) -> TokenStream {
+        let non_snake_case = self.non_snake_case.then(|| quote!(non_snake_case));
+        let deprecated = self.deprecated.then(|| quote!(deprecated));
+        let lints: Vec<TokenStream> = non_snake_case.into_iter().chain(deprecated).collect();
+        if lints.is_empty() {
+            TokenStream::new()
+        } else {
+            quote!(#[allow(#(#lints),*)])
+        }
+    }
+
+    /// Documents the impl block with why fields have no getter, if any reasons were given.
+    fn emit_skip_reasons(&self) -> TokenStream {
+        if self.skip_reasons.is_empty() {
+            return TokenStream::new();
+        }
+
+        let lines = self.skip_reasons
+            .iter()
+            .map(|(name, reason)| format!(" * `{}`: {}", name, reason));
+
+        quote!(
+            #[doc = " Fields without getters;"]
+            #[doc = ""]
+            #(#[doc = #lines])*
+        )
+    }
+
+    /// Names of every method to be generated, in order.
+    fn method_names(&self) -> Vec<Ident> {
+        let mut names: Vec<Ident> = self.constructor
+            .iter()
+            .map(|constructor| constructor.name.clone())
+            .chain(self.default_fn.iter().cloned())
+            .chain(self.snapshot.iter().map(|snapshot| snapshot.name.clone()))
+            .chain(self.field_names.iter().map(|_| format_ident!("field_names")))
+            .chain(self.iter_fields.iter().map(|_| format_ident!("iter_fields")))
+            .collect();
+        for field in self.fields.iter() {
+            names.push(field.getter.clone());
+            names.extend(field.aliases.iter().cloned());
+            if field.iter.is_some() {
+                names.push(format_ident!("{}_iter", field.getter));
+            }
+            if field.indexed.is_some() {
+                names.push(format_ident!("{}_at", field.getter));
+            }
+            if field.eq {
+                names.push(format_ident!("{}_eq", field.getter));
+            }
+            if field.replace {
+                names.push(format_ident!("replace_{}", field.getter));
+            }
+            if field.consume {
+                names.push(format_ident!("into_{}", field.getter));
+            }
+            if field.take.is_some() {
+                names.push(format_ident!("take_{}", field.getter));
+            }
+            if field.hash {
+                names.push(format_ident!("{}_hash", field.getter));
+            }
+            if field.result_split.is_some() {
+                names.push(format_ident!("{}_ok", field.getter));
+                names.push(format_ident!("{}_err", field.getter));
+            }
+            for index in 0..field.elements.as_ref().map(|(_, len)| *len).unwrap_or(0) {
+                names.push(format_ident!("{}_{}", field.getter, index));
+            }
+            if field.map.is_some() {
+                names.push(format_ident!("{}_mapped", field.getter));
+            }
+            if field.const_len.is_some() {
+                names.push(format_ident!("{}_len", field.getter));
+            }
+            if field.map_access.is_some() {
+                names.push(format_ident!("{}_get", field.getter));
+                names.push(format_ident!("{}_contains", field.getter));
+            }
+        }
+        names.extend(self.groups.iter().map(|group| group.name.clone()));
+        names.extend(
+            self.flattened
+                .iter()
+                .flat_map(|flattened| flattened.inner.iter().map(|(name, _)| name.clone()))
+        );
+        names.extend(self.computed.iter().map(|computed| computed.name.clone()));
+
+        names
+    }
+
+    /// Two methods of the same name point at both, the first as a note.
+    fn check_collisions(&self) -> Result<()> {
+        let names = self.method_names();
+        for (i, name) in names.iter().enumerate() {
+            if let Some(first) = names[..i].iter().find(|first| *first == name) {
+                let mut error = Error::new(
+                    name.span(),
+                    Problem::DuplicateGetter(name.to_string()),
+                );
+                error.combine(Error::new(
+                    first.span(),
+                    Problem::FirstGetter(first.to_string()),
+                ));
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// An `# Examples` doc section calling the getter on an instance of the struct.
+    fn doc_example(&self, field: &Field) -> TokenStream {
+        if !self.doc_examples {
+            return TokenStream::new();
+        }
+
+        let instance = snake_case(self.name.to_string().as_str());
+        let call = format!(" let {} = {}.{}();", field.name, instance, field.getter);
+
+        quote!(
+            #[doc = " # Examples"]
+            #[doc = ""]
+            #[doc = " ```ignore"]
+            #[doc = #call]
+            #[doc = " ```"]
+        )
+    }
+}
+
+/// Fields of a tuple struct named by `positional_names`, eg; `x`, `y` and `z` for
+/// `struct Point(f64, f64, f64)`. Other tuple structs have no names for getters.
+fn positional_fields(
+    fields: &Fields,
+    actions: &[StructAction],
+) -> Result<Option<FieldsNamed>> {
+    let names = actions
+        .iter()
+        .rev()
+        .find_map(|action| match action {
+            StructAction::PositionalNames(names) => Some(names),
+            _ => None,
+        });
+    let (unnamed, names) = match (fields, names) {
+        (Fields::Unnamed(unnamed), Some(names)) => (unnamed, names),
+        (Fields::Named(_), Some(_)) => return Err(Error::new(
+            Span::call_site(),
+            Problem::MisplacedOption("positional_names", "a tuple struct"),
+        )),
+        _ => return Ok(None),
+    };
+    if names.len() != unnamed.unnamed.len() {
+        return Err(Error::new(
+            Span::call_site(),
+            Problem::PositionalNamesCount(names.len(), unnamed.unnamed.len()),
+        ));
+    }
+
+    let mut named = Punctuated::new();
+    for (field, name) in unnamed.unnamed.iter().zip(names) {
+        let ident = syn::parse_str::<Ident>(name.value().as_str())
+            .map_err(|_| Error::new(name.span(), Problem::InvalidGetterName(name.value())))?;
+        let mut field = field.clone();
+        field.ident = Some(Ident::new(ident.to_string().as_str(), name.span()));
+        field.colon_token = Some(Default::default());
+        named.push(field);
+    }
+
+    Ok(Some(FieldsNamed { brace_token: Default::default(), named }))
+}
+
+impl<'a> TryFrom<&'a DeriveInput> for NamedStruct<'a> {
+    type Error = Error;
+    
+    fn try_from(node: &'a DeriveInput) -> Result<Self> {
+        let (struct_fields, variant) = struct_or_variant(node)?;
+        let struct_actions = get_struct_actions_from(node.attrs.as_slice())?;
+        let positional = positional_fields(struct_fields, struct_actions.as_slice())?;
+        let named_fields = match &positional {
+            Some(fields_named) => fields_named,
+            None => named_fields(struct_fields)?,
+        };
+        let default = struct_actions
+            .iter()
+            .rev()
+            .find_map(|action| match action {
+                StructAction::All(default) => Some(default.as_ref()),
+                _ => None,
+            });
+        let mut fields = Field::from_fields_named(named_fields, default, positional.is_some())?;
+        for field in fields.iter_mut() {
+            field.variant = variant.cloned();
+        }
+        let skip_reasons = Field::skip_reasons(named_fields)?;
+        let mut groups = Vec::new();
+        let mut flattened = Vec::new();
+        let mut computed = Vec::new();
+        let mut keep_empty_impl = false;
+        let mut doc_examples = false;
+        let mut cfgs = Vec::new();
+        let mut free_fn = false;
+        let mut constructor = None;
+        let mut default_fn = None;
+        let mut snapshot = None;
+        let mut override_where = None;
+        let mut vis: Visibility = parse_quote!(pub);
+        let mut prefix = None;
+        let mut renamer = None;
+        let mut field_names = None;
+        let mut strip = None;
+        let mut iter_fields = None;
+        let mut merge_bounds = false;
+
+        for action in struct_actions {
+            // These build a struct literal or borrow fields by name.
+            let by_name = match &action {
+                StructAction::Group(..) => Some("group"),
+                StructAction::Flatten(_) => Some("flatten"),
+                StructAction::New(_) => Some("new"),
+                StructAction::Snapshot(..) => Some("snapshot"),
+                StructAction::IterFields => Some("iter_fields"),
+                _ => None,
+            };
+            if let (Some(option), Some(_)) = (by_name, &positional) {
+                return Err(Error::new(
+                    Span::call_site(),
+                    Problem::MisplacedOption(option, "a named struct"),
+                ));
+            }
+            // An enum's fields can only be matched out of a borrow of it.
+            let by_struct = match &action {
+                StructAction::ByValueSelf => Some("by_value_self"),
+                _ => by_name,
+            };
+            if let (Some(option), Some(_)) = (by_struct, variant) {
+                return Err(Error::new(
+                    Span::call_site(),
+                    Problem::MisplacedOption(option, "a struct"),
+                ));
+            }
+
+            match action {
+                StructAction::Group(name, members) => {
+                    groups.push(Group::resolve(name, members, named_fields)?);
+                },
+                StructAction::Flatten(flatten) => {
+                    flattened.push(flatten.resolve(named_fields)?);
+                },
+                StructAction::Expr(expr) => computed.push(expr),
+                StructAction::KeepEmptyImpl => keep_empty_impl = true,
+                StructAction::DocExamples => doc_examples = true,
+                StructAction::Cfg(predicate) => cfgs.push(*predicate),
+                StructAction::FreeFn => free_fn = true,
+                StructAction::New(name) => {
+                    constructor = Some(Constructor::new(name, named_fields));
+                },
+                StructAction::DefaultFn(name) => default_fn = Some(name),
+                StructAction::Snapshot(name, path) => {
+                    snapshot = Some(Snapshot::new(name, *path, named_fields));
+                },
+                // These are the names of the fields, not of their getters.
+                StructAction::FieldNames { all } => {
+                    let names: Vec<&Ident> = if all {
+                        named_fields.named.iter().filter_map(|field| field.ident.as_ref()).collect()
+                    } else {
+                        fields.iter().map(|field| &field.name).collect()
+                    };
+                    field_names = Some(
+                        names
+                            .into_iter()
+                            .map(|name| name.to_string().trim_start_matches("r#").to_owned())
+                            .collect()
+                    );
+                },
+                StructAction::IterFields => iter_fields = Some(IterFields::new(named_fields)?),
+                StructAction::EqAll => fields.iter_mut().for_each(|field| field.eq = true),
+                StructAction::ByValueSelf => fields
+                    .iter_mut()
+                    .filter(|field| field.takes_by_value())
+                    .for_each(|field| field.by_value = true),
+                StructAction::OverrideWhere(clause) => override_where = Some(*clause),
+                StructAction::All(_) | StructAction::PositionalNames(_) => (),
+                StructAction::MergeBounds => merge_bounds = true,
+                StructAction::Vis(visibility) => vis = *visibility,
+                StructAction::Prefix(value) => prefix = Some(value),
+                StructAction::StripPrefix(value) => strip = Some(value),
+                StructAction::RenameAll(value) => renamer = Some(value),
+                StructAction::Strict => {
+                    check_misspelled(node.attrs.as_slice())?;
+                    for field in named_fields.named.iter() {
+                        check_misspelled(field.attrs.as_slice())?;
+                    }
+                },
+            }
+        }
+
+        for field in fields.iter_mut() {
+            field.restyle(strip.as_deref(), prefix.as_deref(), renamer.as_ref())?;
+        }
+        let mut fields = Field::ordered(fields)?;
+
+        // Duplicates are left for `where_with` to drop.
+        let merged_bounds = if merge_bounds {
+            fields.iter_mut().flat_map(|field| field.bounds.drain(..)).collect()
+        } else {
+            Vec::new()
+        };
+
+        let non_snake_case = renamer
+            .as_ref()
+            .map(|renamer| !renamer.is_snake())
+            .unwrap_or(false);
+
+        let named_struct = NamedStruct {
+            original: node,
+            name: node.ident.clone(),
+            fields,
+            constructor,
+            default_fn,
+            snapshot,
+            groups,
+            flattened,
+            computed,
+            keep_empty_impl,
+            doc_examples,
+            skip_reasons,
+            cfgs,
+            free_fn,
+            override_where,
+            vis,
+            non_snake_case,
+            deprecated: any_deprecated(struct_fields),
+            field_names,
+            iter_fields,
+            merged_bounds,
+            lifetime: fresh_lifetime("getter", &node.generics),
+        };
+        named_struct.check_collisions()?;
+
+        Ok(named_struct)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_action() -> Result<()> {
+        let a: Action = syn::parse_str("skip")?;
+        assert!(a == Action::Skip(None));
+
+        let a: Action = syn::parse_str("skip = \"internal cache\"")?;
+        assert!(a == Action::Skip(Some("internal cache".to_owned())));
+
+        let r: Result<Action> = syn::parse_str("skip = internal");
+        assert!(r.is_err());
+
+        let r: Result<Action> = syn::parse_str("skip = blah");
+        assert!(r.is_err());
+
+        let a: Action = syn::parse_str("rename = \"hello\"")?;
+        let check = Action::Rename(Ident::new("hello", Span::call_site()));
+        assert!(a == check);
+
+        let a: Action = syn::parse_str("rename = hello")?;
+        assert!(a == check);
+
+        let r: Result<Action> = syn::parse_str("rename + \"chooga\"");        
+        assert!(r.is_err());
+
+        let r: Result<Action> = syn::parse_str("rename = \"chooga\" | bongle");
+        assert!(r.is_err());
+
+        let r: Result<Action> = syn::parse_str("skp");
+        assert!(r.is_err());
+
+        let a: Action = syn::parse_str("as_slice")?;
+        assert!(a == Action::AsSlice);
+
+        let r: Result<Action> = syn::parse_str("as_slice = true");
+        assert!(r.is_err());
+
+        let a: Action = syn::parse_str("try_into = \"std::num::NonZeroU64\"")?;
+        let check = Action::TryInto(Box::new(syn::parse_str("std::num::NonZeroU64")?));
+        assert!(a == check);
+
+        let r: Result<Action> = syn::parse_str("try_into = \"not a type\"");
+        assert!(r.is_err());
+
+        let a: Action = syn::parse_str("body = \"self.inner.as_str()\", ty = \"&str\"")?;
+        let check = Action::Body(
+            Box::new(syn::parse_str("self.inner.as_str()")?),
+            Box::new(syn::parse_str("&str")?),
+        );
+        assert!(a == check);
+
+        let r: Result<Action> = syn::parse_str("body = \"self.inner.as_str()\"");
+        assert!(r.is_err());
+
+        let a: Action = syn::parse_str("lock")?;
+        assert!(a == Action::Lock { try_lock: false });
+
+        let a: Action = syn::parse_str("lock = \"try\"")?;
+        assert!(a == Action::Lock { try_lock: true });
+
+        let r: Result<Action> = syn::parse_str("lock = \"spin\"");
+        assert!(r.is_err());
+
+        let a: Action = syn::parse_str("iter")?;
+        assert!(a == Action::Iter);
+
+        let a: Action = syn::parse_str("ptr")?;
+        assert!(a == Action::Ptr { mutable: false });
+
+        let a: Action = syn::parse_str("ptr_mut")?;
+        assert!(a == Action::Ptr { mutable: true });
+
+        let a: Action = syn::parse_str("manually_drop")?;
+        assert!(a == Action::ManuallyDrop);
+
+        let a: Action = syn::parse_str("unwrap")?;
+        assert!(a == Action::Unwrap);
+
+        let a: Action = syn::parse_str("ref")?;
+        assert!(a == Action::Ref);
+
+        let a: Action = syn::parse_str("copy")?;
+        assert!(a == Action::Copy);
+
+        let a: Action = syn::parse_str("clone")?;
+        assert!(a == Action::Clone);
+
+        let a: Action = syn::parse_str("as_ref")?;
+        assert!(a == Action::AsRef);
+
+        let a: Action = syn::parse_str("const_len")?;
+        assert!(a == Action::ConstLen);
+
+        let a: Action = syn::parse_str("rename_from_type")?;
+        assert!(a == Action::RenameFromType);
+
+        let a: Action = syn::parse_str("map_access")?;
+        assert!(a == Action::MapAccess);
+
+        let a: Action = syn::parse_str("keep")?;
+        assert!(a == Action::Keep);
+
+        let a: Action = syn::parse_str("pin_ref")?;
+        assert!(a == Action::PinRef);
+
+        let a: Action = syn::parse_str("doc_alias = \"width\"")?;
+        assert!(a == Action::DocAlias("width".to_string()));
+
+        let r: Result<Action> = syn::parse_str("doc_alias = width");
+        assert!(r.is_err());
+
+        let a: Action = syn::parse_str("map = \"to_uppercase\", map_ty = \"String\"")?;
+        let check = Action::Map(
+            Ident::new("to_uppercase", Span::call_site()),
+            Box::new(syn::parse_str("String")?),
+        );
+        assert!(a == check);
+
+        let r: Result<Action> = syn::parse_str("map = \"to_uppercase\"");
+        assert!(r.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_struct_action() -> Result<()> {
+        let a: StructAction = syn::parse_str("group(point, x, y)")?;
+        let check = StructAction::Group(
+            Ident::new("point", Span::call_site()),
+            vec![Ident::new("x", Span::call_site()), Ident::new("y", Span::call_site())],
+        );
+        assert!(a == check);
+
+        let r: Result<StructAction> = syn::parse_str("group(point)");
+        assert!(r.is_err());
+
+        let r: Result<StructAction> = syn::parse_str("group()");
+        assert!(r.is_err());
+
+        let r: Result<StructAction> = syn::parse_str("skip");
+        assert!(r.is_err());
+
+        let a: StructAction = syn::parse_str("doc_examples")?;
+        assert!(a == StructAction::DocExamples);
+
+        let a: StructAction = syn::parse_str("cfg = \"not(test)\"")?;
+        assert!(a == StructAction::Cfg(Box::new(syn::parse_str("not(test)")?)));
+
+        let r: Result<StructAction> = syn::parse_str("cfg = \"not(\"");
+        assert!(r.is_err());
+
+        let a: StructAction = syn::parse_str("free_fn")?;
+        assert!(a == StructAction::FreeFn);
+
+        let a: StructAction = syn::parse_str("all = \"copy\"")?;
+        assert!(a == StructAction::All(Box::new(Action::Copy)));
+
+        let r: Result<StructAction> = syn::parse_str("all = \"skip\"");
+        assert!(r.is_err());
+
+        let a: StructAction = syn::parse_str("override_where = \"T: Clone\"")?;
+        let check: WhereClause = syn::parse_str("where T: Clone")?;
+        assert!(a == StructAction::OverrideWhere(Box::new(check.clone())));
+
+        let a: StructAction = syn::parse_str("override_where = \"where T: Clone\"")?;
+        assert!(a == StructAction::OverrideWhere(Box::new(check)));
+
+        let r: Result<StructAction> = syn::parse_str("override_where = \"T Clone\"");
+        assert!(r.is_err());
+
+        let a: StructAction = syn::parse_str(
+            "expr(area = \"self.width * self.height\", ty = \"u64\")"
+        )?;
+        let check = StructAction::Expr(Computed {
+            name: Ident::new("area", Span::call_site()),
+            expr: Box::new(syn::parse_str("self.width * self.height")?),
+            ty: Box::new(syn::parse_str("u64")?),
+        });
+        assert!(a == check);
+
+        let r: Result<StructAction> = syn::parse_str(
+            "expr(area = \"self.width *\", ty = \"u64\")"
+        );
+        assert!(r.is_err());
+
+        let r: Result<StructAction> = syn::parse_str("expr(area = \"self.width\")");
+        assert!(r.is_err());
+
+        let a: StructAction = syn::parse_str("vis = \"pub(crate)\"")?;
+        assert!(a == StructAction::Vis(Box::new(syn::parse_str("pub(crate)")?)));
+
+        let a: StructAction = syn::parse_str("prefix = \"get_\"")?;
+        assert!(a == StructAction::Prefix("get_".to_string()));
+
+        let a: StructAction = syn::parse_str("rename_all = \"camelCase\"")?;
+        assert!(a == StructAction::RenameAll(Renamer::Case(Case::Camel)));
+
+        let a: StructAction = syn::parse_str("rename_all = \"template:{}_value\"")?;
+        assert!(a == StructAction::RenameAll(Renamer::Template("{}_value".to_string())));
+
+        let r: Result<StructAction> = syn::parse_str("rename_all = \"template:value\"");
+        assert!(r.is_err());
+
+        let r: Result<StructAction> = syn::parse_str("rename_all = \"template:{}_{}\"");
+        assert!(r.is_err());
+
+        let r: Result<StructAction> = syn::parse_str("rename_all = \"snakecase\"");
+        assert!(r.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn case_apply() {
+        assert!(Case::Snake.apply("first_name") == "first_name");
+        assert!(Case::Lower.apply("first_name") == "first_name");
+        assert!(Case::Upper.apply("first_name") == "FIRST_NAME");
+        assert!(Case::Pascal.apply("first_name") == "FirstName");
+        assert!(Case::Camel.apply("first_name") == "firstName");
+        assert!(Case::ScreamingSnake.apply("first_name") == "FIRST_NAME");
+        assert!(Case::Snake.apply("FirstName") == "first_name");
+        assert!(Case::Snake.apply("HTTPServer") == "http_server");
+        assert!(Case::Camel.apply("HTTPServer") == "httpServer");
+
+        let template = Renamer::Template("get_{}".to_string());
+        assert!(template.apply("first_name") == "get_first_name");
+        assert!(template.is_snake());
+        assert!(!Renamer::Template("{}Value".to_string()).is_snake());
+    }
+
+    #[test]
+    fn emit_struct_options() -> Result<()> {
+        let combined: DeriveInput = syn::parse_str(
+            "#[getter(vis = \"pub(crate)\", prefix = \"get_\", rename_all = \"snake_case\")]
+             struct Combined { first_name: String, #[getter(rename = \"surname\")] last_name: String }"
+        )?;
+        let emitted = NamedStruct::try_from(&combined)?.emit().to_string();
+        assert!(emitted.contains("pub (crate) fn get_first_name (& self) -> & String"));
+        assert!(emitted.contains("pub (crate) fn surname (& self) -> & String"));
+        assert!(!emitted.contains("pub fn"));
+
+        let separate: DeriveInput = syn::parse_str(
+            "#[getter(prefix = \"get_\")] #[getter(vis = \"pub(super)\")] struct S { a: u64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&separate)?.emit().to_string();
+        assert!(emitted.contains("pub (super) fn get_a (& self) -> & u64"));
+
+        let invalid: DeriveInput = syn::parse_str(
+            "#[getter(prefix = \"get-\")] struct Invalid { a: u64 }"
+        )?;
+        assert!(NamedStruct::try_from(&invalid).is_err());
+
+        let trailing: DeriveInput = syn::parse_str(
+            "#[getter(vis = \"pub(crate)\" prefix = \"get_\")] struct Trailing { a: u64 }"
+        )?;
+        assert!(NamedStruct::try_from(&trailing).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_rename_template() -> Result<()> {
+        let suffixed: DeriveInput = syn::parse_str(
+            "#[getter(rename_all = \"template:{}_value\")] struct S { foo: u64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&suffixed)?.emit().to_string();
+        assert!(emitted.contains("pub fn foo_value (& self) -> & u64"));
+
+        let prefixed: DeriveInput = syn::parse_str(
+            "#[getter(rename_all = \"template:get_{}\")] struct P { foo: u64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&prefixed)?.emit().to_string();
+        assert!(emitted.contains("pub fn get_foo (& self) -> & u64"));
+
+        let invalid: DeriveInput = syn::parse_str(
+            "#[getter(rename_all = \"template:{}-value\")] struct I { foo: u64 }"
+        )?;
+        assert!(NamedStruct::try_from(&invalid).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_strip_prefix() -> Result<()> {
+        let a: StructAction = syn::parse_str("strip_prefix = \"user_\"")?;
+        assert!(a == StructAction::StripPrefix("user_".to_string()));
+
+        let user: DeriveInput = syn::parse_str(
+            "#[getter(strip_prefix = \"user_\", prefix = \"get_\")] \
+             struct User { user_name: String, user_type: u8, id: u64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&user)?.emit().to_string();
+        assert!(emitted.contains("pub fn get_name (& self) -> & String { & self . user_name }"));
+        assert!(emitted.contains("pub fn get_type (& self)"));
+        assert!(emitted.contains("pub fn get_id (& self)"));
+
+        let keyword: DeriveInput = syn::parse_str(
+            "#[getter(strip_prefix = \"user_\")] struct User { user_type: u8 }"
+        )?;
+        let emitted = NamedStruct::try_from(&keyword)?.emit().to_string();
+        assert!(emitted.contains("pub fn r#type (& self)"));
+
+        let colliding: DeriveInput = syn::parse_str(
+            "#[getter(strip_prefix = \"user_\")] struct User { user_name: String, name: String }"
+        )?;
+        assert!(NamedStruct::try_from(&colliding).is_err());
+
+        let emptied: DeriveInput = syn::parse_str(
+            "#[getter(strip_prefix = \"user_\")] struct User { user_: String }"
+        )?;
+        assert!(NamedStruct::try_from(&emptied).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_empty() -> Result<()> {
+        let skipped: DeriveInput = syn::parse_str(
+            "struct Skipped { #[getter(skip)] a: u64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&skipped)?.emit();
+        assert!(emitted.is_empty());
+
+        let kept: DeriveInput = syn::parse_str(
+            "#[getter(keep_empty_impl)] struct Kept { #[getter(skip)] a: u64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&kept)?.emit().to_string();
+        assert!(emitted == "impl Kept { }");
+
+        let reasoned: DeriveInput = syn::parse_str(
+            "struct Reasoned { a: u64, #[getter(skip = \"internal cache\")] b: u64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&reasoned)?.emit().to_string();
+        assert!(emitted.contains("# [doc = \" * `b`: internal cache\"]"));
+        assert!(!emitted.contains("pub fn b"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_allow_deprecated() -> Result<()> {
+        let deprecated: DeriveInput = syn::parse_str(
+            "#[getter(new)] struct Old { #[deprecated] #[getter(skip)] a: u64, b: u64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&deprecated)?.emit().to_string();
+        assert!(emitted.starts_with("# [allow (deprecated)] impl Old"));
+
+        let current: DeriveInput = syn::parse_str("#[getter(new)] struct New { a: u64 }")?;
+        let emitted = NamedStruct::try_from(&current)?.emit().to_string();
+        assert!(!emitted.contains("deprecated"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn collisions() -> Result<()> {
+        let renamed: DeriveInput = syn::parse_str(
+            "struct Renamed { a: u64, #[getter(rename = \"a\")] b: u64 }"
+        )?;
+        let error = NamedStruct::try_from(&renamed).err().unwrap();
+        assert!(error.into_iter().count() == 2);
+
+        let iterated: DeriveInput = syn::parse_str(
+            "struct Iterated { #[getter(iter)] a: Vec<u8>, a_iter: u8 }"
+        )?;
+        assert!(NamedStruct::try_from(&iterated).is_err());
+
+        let grouped: DeriveInput = syn::parse_str(
+            "#[getter(group(a, b))] struct Grouped { a: u64, b: u64 }"
+        )?;
+        assert!(NamedStruct::try_from(&grouped).is_err());
+
+        let skipped: DeriveInput = syn::parse_str(
+            "struct Skipped { #[getter(skip)] a: u64, #[getter(rename = \"a\")] b: u64 }"
+        )?;
+        assert!(NamedStruct::try_from(&skipped).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_rename_from_type() -> Result<()> {
+        let typed: DeriveInput = syn::parse_str(
+            "struct App<'a> { #[getter(rename_from_type)] c: crate::Config, \
+             #[getter(rename_from_type)] l: &'a HttpLimits }"
+        )?;
+        let emitted = NamedStruct::try_from(&typed)?.emit().to_string();
+        assert!(emitted.contains("pub fn config (& self) -> & crate :: Config"));
+        assert!(emitted.contains("pub fn http_limits (& 'a self) -> & 'a HttpLimits"));
+
+        let colliding: DeriveInput = syn::parse_str(
+            "struct App { config: u8, #[getter(rename_from_type)] c: Config }"
+        )?;
+        assert!(NamedStruct::try_from(&colliding).is_err());
+
+        let unnamed: DeriveInput = syn::parse_str(
+            "struct App { #[getter(rename_from_type)] pair: (u8, u8) }"
+        )?;
+        assert!(NamedStruct::try_from(&unnamed).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_const_len() -> Result<()> {
+        let sized: DeriveInput = syn::parse_str(
+            "struct Key<const N: usize> { #[getter(const_len)] a: [u8; 32], \
+             #[getter(const_len)] b: [u8; N] }"
+        )?;
+        let emitted = NamedStruct::try_from(&sized)?.emit().to_string();
+        assert!(emitted.contains("pub const fn a_len () -> usize { 32 }"));
+        assert!(emitted.contains("pub const fn b_len () -> usize { N }"));
+
+        let unsized_field: DeriveInput = syn::parse_str(
+            "struct Key { #[getter(const_len)] a: Vec<u8> }"
+        )?;
+        assert!(NamedStruct::try_from(&unsized_field).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_indexed() -> Result<()> {
+        let a: Action = syn::parse_str("indexed")?;
+        assert!(a == Action::Indexed);
+
+        let listed: DeriveInput = syn::parse_str(
+            "struct L<'a> { #[getter(indexed)] a: Vec<String>, \
+             #[getter(indexed)] #[getter(rename = \"bytes\")] b: &'a [u8] }"
+        )?;
+        let emitted = NamedStruct::try_from(&listed)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub fn a_at (& self , index : usize) -> :: core :: option :: Option < & String > \
+             { self . a . get (index) }"
+        ));
+        assert!(emitted.contains("pub fn bytes_at (& self , index : usize)"));
+
+        let scalar: DeriveInput = syn::parse_str("struct S { #[getter(indexed)] a: u8 }")?;
+        assert!(NamedStruct::try_from(&scalar).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_replace() -> Result<()> {
+        let a: Action = syn::parse_str("replace")?;
+        assert!(a == Action::Replace);
+
+        let replacing: DeriveInput = syn::parse_str(
+            "struct S { #[getter(replace)] a: String, #[getter(replace)] #[getter(rename = \"c\")] b: u8 }"
+        )?;
+        let emitted = NamedStruct::try_from(&replacing)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub fn replace_a (& mut self , value : String) -> String \
+             { :: core :: mem :: replace (& mut self . a , value) }"
+        ));
+        assert!(emitted.contains("pub fn replace_c (& mut self , value : u8) -> u8"));
+
+        let colliding: DeriveInput = syn::parse_str(
+            "struct S { #[getter(replace)] a: u8, replace_a: bool }"
+        )?;
+        assert!(NamedStruct::try_from(&colliding).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_consume() -> Result<()> {
+        let a: Action = syn::parse_str("consume")?;
+        assert!(a == Action::Consume);
+
+        let consuming: DeriveInput = syn::parse_str(
+            "struct S { #[getter(consume)] a: String, #[getter(consume)] #[getter(rename = \"c\")] b: u8 }"
+        )?;
+        let emitted = NamedStruct::try_from(&consuming)?.emit().to_string();
+        assert!(emitted.contains("pub fn into_a (self) -> String { self . a }"));
+        assert!(emitted.contains("pub fn into_c (self) -> u8 { self . b }"));
+
+        let variant: DeriveInput = syn::parse_str(
+            "enum E { Only { #[getter(consume)] a: String } }"
+        )?;
+        let emitted = NamedStruct::try_from(&variant)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub fn into_a (self) -> String { match self { Self :: Only { a , .. } => a } }"
+        ));
+
+        let colliding: DeriveInput = syn::parse_str(
+            "struct S { #[getter(consume)] a: u8, into_a: bool }"
+        )?;
+        assert!(NamedStruct::try_from(&colliding).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_take() -> Result<()> {
+        let a: Action = syn::parse_str("take")?;
+        assert!(a == Action::Take);
+
+        let taking: DeriveInput = syn::parse_str(
+            "struct S { #[getter(take)] a: Option<String>, #[getter(take)] b: Vec<u8> }"
+        )?;
+        let emitted = NamedStruct::try_from(&taking)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub fn take_a (& mut self) -> Option < String > { self . a . take () }"
+        ));
+        assert!(emitted.contains(
+            "pub fn take_b (& mut self) -> Vec < u8 > { :: core :: mem :: take (& mut self . b) }"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_hash() -> Result<()> {
+        let a: Action = syn::parse_str("hash")?;
+        assert!(a == Action::Hash);
+
+        let hashing: DeriveInput = syn::parse_str(
+            "struct S { #[getter(hash)] a: String, b: u8 }"
+        )?;
+        let emitted = NamedStruct::try_from(&hashing)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub fn a_hash (& self) -> u64 \
+             { let mut hasher = :: std :: collections :: hash_map :: DefaultHasher :: new () ; \
+             :: core :: hash :: Hash :: hash (& self . a , & mut hasher) ; \
+             :: core :: hash :: Hasher :: finish (& hasher) }"
+        ));
+        assert!(!emitted.contains("b_hash"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_gat_lifetime() -> Result<()> {
+        let a: Action = syn::parse_str("gat_lifetime")?;
+        assert!(a == Action::GatLifetime);
+
+        let named: DeriveInput = syn::parse_str(
+            "struct S<'a, T> { #[getter(gat_lifetime)] a: &'a T, #[getter(gat_lifetime)] b: Vec<T> }"
+        )?;
+        let emitted = NamedStruct::try_from(&named)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub fn b < 'getter > (& 'getter self) -> & 'getter Vec < T > where Self : 'getter \
+             { & self . b }"
+        ));
+        assert!(emitted.contains(
+            "pub fn a < 'getter > (& 'getter self) -> & 'a T where Self : 'getter { self . a }"
+        ));
+
+        let shadowed: DeriveInput = syn::parse_str(
+            "#[getter(free_fn)] struct S<'getter> { #[getter(gat_lifetime)] a: &'getter str }"
+        )?;
+        let emitted = NamedStruct::try_from(&shadowed)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub fn a < 'getter_ > (& 'getter_ self) -> & 'getter str where Self : 'getter_"
+        ));
+        assert!(emitted.contains("pub fn s_a < 'getter_ , 'getter > (s : & 'getter_ S < 'getter >)"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_array_full() -> Result<()> {
+        let a: Action = syn::parse_str("array_full")?;
+        assert!(a == Action::ArrayFull);
+
+        let rgb: DeriveInput = syn::parse_str("struct S { #[getter(array_full)] rgb: [u8; 3] }")?;
+        let emitted = NamedStruct::try_from(&rgb)?.emit().to_string();
+        assert!(emitted.contains("pub fn rgb (& self) -> & [u8] { & self . rgb }"));
+        assert!(emitted.contains("pub fn rgb_0 (& self) -> & u8 { & self . rgb [0] }"));
+        assert!(emitted.contains("pub fn rgb_2 (& self) -> & u8 { & self . rgb [2] }"));
+        assert!(!emitted.contains("rgb_3"));
+
+        let generic: DeriveInput = syn::parse_str(
+            "struct S<const N: usize> { #[getter(array_full)] a: [u8; N] }"
+        )?;
+        let error = NamedStruct::try_from(&generic).err().unwrap();
+        assert!(error.to_string() == "array_full needs the array's length to be an integer literal");
+
+        let vec: DeriveInput = syn::parse_str("struct S { #[getter(array_full)] a: Vec<u8> }")?;
+        assert!(NamedStruct::try_from(&vec).is_err());
+
+        let conflicting: DeriveInput = syn::parse_str(
+            "struct S { #[getter(array_full)] #[getter(copy)] a: [u8; 2] }"
+        )?;
+        assert!(NamedStruct::try_from(&conflicting).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_result_split() -> Result<()> {
+        let a: Action = syn::parse_str("result_split")?;
+        assert!(a == Action::ResultSplit);
+
+        let split: DeriveInput = syn::parse_str(
+            "struct S { #[getter(result_split)] a: Result<u32, String> }"
+        )?;
+        let emitted = NamedStruct::try_from(&split)?.emit().to_string();
+        assert!(emitted.contains("pub fn a (& self) -> & Result < u32 , String >"));
+        assert!(emitted.contains(
+            "pub fn a_ok (& self) -> :: core :: option :: Option < & u32 > \
+             { self . a . as_ref () . ok () }"
+        ));
+        assert!(emitted.contains(
+            "pub fn a_err (& self) -> :: core :: option :: Option < & String > \
+             { self . a . as_ref () . err () }"
+        ));
+
+        let option: DeriveInput = syn::parse_str(
+            "struct S { #[getter(result_split)] a: Option<u32> }"
+        )?;
+        let error = NamedStruct::try_from(&option).err().unwrap();
+        assert!(error.to_string() == "result_split can only be used on a `Result` field");
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_eq() -> Result<()> {
+        let a: Action = syn::parse_str("eq")?;
+        assert!(a == Action::Eq);
+
+        let a: StructAction = syn::parse_str("eq_all")?;
+        assert!(a == StructAction::EqAll);
+
+        let single: DeriveInput = syn::parse_str(
+            "struct S { #[getter(eq)] a: String, b: u8 }"
+        )?;
+        let emitted = NamedStruct::try_from(&single)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub fn a_eq < Q : ? :: core :: marker :: Sized > (& self , other : & Q) -> bool \
+             where String : :: core :: cmp :: PartialEq < Q > \
+             { :: core :: cmp :: PartialEq :: eq (& self . a , other) }"
+        ));
+        assert!(!emitted.contains("b_eq"));
+
+        let all: DeriveInput = syn::parse_str(
+            "#[getter(eq_all)] struct S { a: String, #[getter(skip)] b: u8, #[getter(rename = \"d\")] c: u8 }"
+        )?;
+        let emitted = NamedStruct::try_from(&all)?.emit().to_string();
+        assert!(emitted.contains("pub fn a_eq"));
+        assert!(emitted.contains("pub fn d_eq"));
+        assert!(!emitted.contains("b_eq"));
+
+        let colliding: DeriveInput = syn::parse_str(
+            "#[getter(eq_all)] struct S { a: u8, a_eq: bool }"
+        )?;
+        assert!(NamedStruct::try_from(&colliding).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_map_access() -> Result<()> {
+        let scores: DeriveInput = syn::parse_str(
+            "struct Scores { #[getter(map_access)] by_name: HashMap<String, u32> }"
+        )?;
+        let emitted = NamedStruct::try_from(&scores)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub fn by_name_get (& self , key : & String) -> :: core :: option :: Option < & u32 >"
+        ));
+        assert!(emitted.contains("pub fn by_name_contains (& self , key : & String) -> bool"));
+
+        let listed: DeriveInput = syn::parse_str(
+            "struct Listed { #[getter(map_access)] names: Vec<String> }"
+        )?;
+        assert!(NamedStruct::try_from(&listed).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_unit_fields() -> Result<()> {
+        let unit: DeriveInput = syn::parse_str(
+            "struct Unit { a: u64, marker: (), #[getter(keep)] kept: () }"
+        )?;
+        let emitted = NamedStruct::try_from(&unit)?.emit().to_string();
+        assert!(emitted.contains("pub fn a (& self)"));
+        assert!(!emitted.contains("pub fn marker"));
+        assert!(emitted.contains("pub fn kept (& self) -> & ()"));
+
+        let only: DeriveInput = syn::parse_str("struct Only { marker: () }")?;
+        assert!(NamedStruct::try_from(&only)?.emit().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_aliases() -> Result<()> {
+        let a: Action = syn::parse_str("alias = \"colour\"")?;
+        assert!(a == Action::Alias(Ident::new("colour", Span::call_site())));
+
+        let paint: DeriveInput = syn::parse_str(
+            "struct Paint { #[getter(rename = \"color\")] #[getter(rename = \"colour\")] \
+             #[getter(alias = hue)] c: u32 }"
+        )?;
+        let emitted = NamedStruct::try_from(&paint)?.emit().to_string();
+        assert!(emitted.contains("fn color (& self) -> & u32 { & self . c }"));
+        assert!(emitted.contains("fn colour (& self) -> & u32 { self . color () }"));
+        assert!(emitted.contains("fn hue (& self) -> & u32 { self . color () }"));
+
+        let colliding: DeriveInput = syn::parse_str(
+            "struct Paint { #[getter(alias = \"d\")] c: u32, d: u32 }"
+        )?;
+        assert!(NamedStruct::try_from(&colliding).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_doc_aliases() -> Result<()> {
+        let aliased: DeriveInput = syn::parse_str(
+            "struct Size { #[getter(doc_alias = \"width\")] #[getter(doc_alias = \"x\")] w: u64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&aliased)?.emit().to_string();
+        assert!(emitted.contains(
+            "# [doc (alias = \"width\")] # [doc (alias = \"x\")] pub fn w (& self)"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_doc_examples() -> Result<()> {
+        let documented: DeriveInput = syn::parse_str(
+            "#[getter(doc_examples)] struct HttpRequest { #[getter(rename = \"path\")] uri: String }"
+        )?;
+        let emitted = NamedStruct::try_from(&documented)?.emit().to_string();
+        assert!(emitted.contains("# [doc = \" # Examples\"]"));
+        assert!(emitted.contains("# [doc = \" let uri = http_request.path();\"]"));
+
+        let plain: DeriveInput = syn::parse_str("struct Plain { a: u64 }")?;
+        let emitted = NamedStruct::try_from(&plain)?.emit().to_string();
+        assert!(!emitted.contains("doc"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_single_variant() -> Result<()> {
+        let node: DeriveInput = syn::parse_str("enum Only { Variant { a: u32 } }")?;
+        let emitted = NamedStruct::try_from(&node)?.emit().to_string();
+        let body = "& (* match self { Self :: Variant { a , .. } => a })";
+        assert!(emitted.contains(format!("pub fn a (& self) -> & u32 {{ {} }}", body).as_str()));
+
+        let node: DeriveInput = syn::parse_str("enum Two { A { a: u32 }, B { a: u32 } }")?;
+        assert!(NamedStruct::try_from(&node).is_err());
+
+        let node: DeriveInput = syn::parse_str(
+            "#[getter(by_value_self)] enum Only { Variant { a: u32 } }"
+        )?;
+        assert!(NamedStruct::try_from(&node).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_flatten() -> Result<()> {
+        let a: StructAction = syn::parse_str("flatten(common: [a: u32, b: String])")?;
+        let check = StructAction::Flatten(Flatten {
+            field: Ident::new("common", Span::call_site()),
+            inner: vec![
+                (Ident::new("a", Span::call_site()), syn::parse_str("u32")?),
+                (Ident::new("b", Span::call_site()), syn::parse_str("String")?),
+            ],
+        });
+        assert!(a == check);
+
+        let r: Result<StructAction> = syn::parse_str("flatten(common: [])");
+        assert!(r.is_err());
+
+        let r: Result<StructAction> = syn::parse_str("flatten(common: [a u32])");
+        assert!(r.is_err());
+
+        let outer: DeriveInput = syn::parse_str(
+            "#[getter(flatten(common: [a: u32, b: String]))] struct Outer { common: Common, extra: u32 }"
+        )?;
+        let emitted = NamedStruct::try_from(&outer)?.emit().to_string();
+        assert!(emitted.contains("pub fn a (& self) -> & u32 { & self . common . a }"));
+        assert!(emitted.contains("pub fn b (& self) -> & String { & self . common . b }"));
+
+        let colliding: DeriveInput = syn::parse_str(
+            "#[getter(flatten(common: [extra: u32]))] struct Outer { common: Common, extra: u32 }"
+        )?;
+        assert!(NamedStruct::try_from(&colliding).is_err());
+
+        let missing: DeriveInput = syn::parse_str(
+            "#[getter(flatten(shared: [a: u32]))] struct Outer { common: Common, extra: u32 }"
+        )?;
+        assert!(NamedStruct::try_from(&missing).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn strict() -> Result<()> {
+        let a: StructAction = syn::parse_str("strict")?;
+        assert!(a == StructAction::Strict);
+
+        let lenient: DeriveInput = syn::parse_str(
+            "struct Lenient { #[getters(skip)] a: u64 }"
+        )?;
+        assert!(NamedStruct::try_from(&lenient).is_ok());
+
+        let strict: DeriveInput = syn::parse_str(
+            "#[getter(strict)] struct Strict { #[getters(skip)] a: u64 }"
+        )?;
+        assert!(NamedStruct::try_from(&strict).is_err());
+
+        let strict: DeriveInput = syn::parse_str(
+            "#[getter(strict)] struct Strict { #[get_ter(skip)] a: u64 }"
+        )?;
+        assert!(NamedStruct::try_from(&strict).is_err());
+
+        let foreign: DeriveInput = syn::parse_str(
+            "#[getter(strict)] struct Foreign { #[serde(skip)] #[getter(copy)] a: u64 }"
+        )?;
+        assert!(NamedStruct::try_from(&foreign).is_ok());
+
+        let setter: DeriveInput = syn::parse_str(
+            "#[getter(strict)] #[setters(prefix = \"set_\")] struct Setter { #[setter(skip)] a: u64 }"
+        )?;
+        assert!(NamedStruct::try_from(&setter).is_ok());
+
+        assert!(is_getter_typo("Getters") && is_getter_typo("GET_TER"));
+        assert!(!is_getter_typo("setter") && !is_getter_typo("gutter"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn ignores_foreign_attributes() -> Result<()> {
+        let plain: DeriveInput = syn::parse_str("struct Plain { a: u64 }")?;
+        let plain = NamedStruct::try_from(&plain)?.emit().to_string();
+
+        for attributes in &["#[repr(C)]", "#[repr(transparent)]", "#[repr(C, align(8))]"] {
+            let source = format!("{} struct Plain {{ #[doc = \"a\"] a: u64 }}", attributes);
+            let node: DeriveInput = syn::parse_str(source.as_str())?;
+            assert!(NamedStruct::try_from(&node)?.emit().to_string() == plain);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_as_dyn() -> Result<()> {
+        let a: Action = syn::parse_str("as_dyn = \"dyn Handler\"")?;
+        assert!(a == Action::AsDyn(Box::new(syn::parse_str("dyn Handler")?)));
+
+        let r: Result<Action> = syn::parse_str("as_dyn = \"Handler\"");
+        assert!(r.is_err());
+
+        let served: DeriveInput = syn::parse_str(
+            "struct S { #[getter(as_dyn = \"dyn Handler\")] a: Concrete, \
+             #[getter(as_dyn = \"dyn Handler + Send\")] b: Concrete }"
+        )?;
+        let emitted = NamedStruct::try_from(&served)?.emit().to_string();
+        assert!(emitted.contains("pub fn a (& self) -> & dyn Handler { & self . a }"));
+        assert!(emitted.contains("pub fn b (& self) -> & (dyn Handler + Send) { & self . b }"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_deref() -> Result<()> {
+        let a: Action = syn::parse_str("deref = \"str\"")?;
+        assert!(a == Action::Deref(Box::new(syn::parse_str("str")?)));
+
+        let r: Result<Action> = syn::parse_str("deref");
+        assert!(r.is_err());
+
+        let wrapped: DeriveInput = syn::parse_str(
+            "struct W { #[getter(deref = \"[u8]\")] a: Bytes, #[getter(deref = \"Inner<T>\")] b: Outer }"
+        )?;
+        let emitted = NamedStruct::try_from(&wrapped)?.emit().to_string();
+        assert!(emitted.contains("pub fn a (& self) -> & [u8] { & * self . a }"));
+        assert!(emitted.contains("pub fn b (& self) -> & Inner < T > { & * self . b }"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_upgrade() -> Result<()> {
+        let a: Action = syn::parse_str("upgrade")?;
+        assert!(a == Action::Upgrade { rc: None });
+
+        let a: Action = syn::parse_str("upgrade = \"rc\"")?;
+        assert!(a == Action::Upgrade { rc: Some(true) });
+
+        let r: Result<Action> = syn::parse_str("upgrade = \"box\"");
+        assert!(r.is_err());
+
+        let weak: DeriveInput = syn::parse_str(
+            "struct W { #[getter(upgrade)] a: std::sync::Weak<u32>, \
+             #[getter(upgrade)] b: std::rc::Weak<u8>, \
+             #[getter(upgrade = \"rc\")] c: Weak<u16>, \
+             #[getter(upgrade)] d: Weak<u64> }"
+        )?;
+        let emitted = NamedStruct::try_from(&weak)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub fn a (& self) -> :: core :: option :: Option < :: std :: sync :: Arc < u32 >> \
+             { self . a . upgrade () }"
+        ));
+        assert!(emitted.contains("Option < :: std :: rc :: Rc < u8 >>"));
+        assert!(emitted.contains("Option < :: std :: rc :: Rc < u16 >>"));
+        assert!(emitted.contains("Option < :: std :: sync :: Arc < u64 >>"));
+
+        let strong: DeriveInput = syn::parse_str("struct S { #[getter(upgrade)] a: Arc<u32> }")?;
+        assert!(NamedStruct::try_from(&strong).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_once() -> Result<()> {
+        let a: Action = syn::parse_str("once")?;
+        assert!(a == Action::Once);
+
+        let lazy: DeriveInput = syn::parse_str(
+            "struct L { #[getter(once)] a: OnceCell<String>, #[getter(once)] b: std::sync::OnceLock<u8> }"
+        )?;
+        let emitted = NamedStruct::try_from(&lazy)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub fn a (& self) -> :: core :: option :: Option < & String > { self . a . get () }"
+        ));
+        assert!(emitted.contains("pub fn b (& self) -> :: core :: option :: Option < & u8 >"));
+
+        let plain: DeriveInput = syn::parse_str("struct P { #[getter(once)] a: String }")?;
+        assert!(NamedStruct::try_from(&plain).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_unwrap() -> Result<()> {
+        let unwrapped: DeriveInput = syn::parse_str(
+            "struct U { #[getter(unwrap)] a: Option<String>, #[getter(unwrap)] b: Result<u8, E> }"
+        )?;
+        let emitted = NamedStruct::try_from(&unwrapped)?.emit().to_string();
+        assert!(emitted.contains(
+            "# [doc = \" If the field is `None`.\"] pub fn a (& self) -> & String"
+        ));
+        assert!(emitted.contains(
+            "# [doc = \" If the field is `Err`.\"] pub fn b (& self) -> & u8"
+        ));
+
+        let plain: DeriveInput = syn::parse_str("struct P { #[getter(unwrap)] a: String }")?;
+        assert!(NamedStruct::try_from(&plain).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_default_mode() -> Result<()> {
+        let copied: DeriveInput = syn::parse_str(
+            "#[getter(all = \"copy\")] struct C { a: u64, #[getter(ref)] b: String }"
+        )?;
+        let emitted = NamedStruct::try_from(&copied)?.emit().to_string();
+        assert!(emitted.contains("pub fn a (& self) -> u64 { self . a }"));
+        assert!(emitted.contains("pub fn b (& self) -> & String { & self . b }"));
+
+        let conflicting: DeriveInput = syn::parse_str(
+            "struct C { #[getter(ref)] #[getter(copy)] a: u64 }"
+        )?;
+        assert!(NamedStruct::try_from(&conflicting).is_err());
+
+        let not_option: DeriveInput = syn::parse_str(
+            "#[getter(all = \"as_ref\")] struct C { a: Option<u64>, b: u64 }"
+        )?;
+        assert!(NamedStruct::try_from(&not_option).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_cfg() -> Result<()> {
+        let guarded: DeriveInput = syn::parse_str(
+            "#[getter(cfg = \"not(test)\")] struct Guarded { a: u64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&guarded)?.emit().to_string();
+        assert!(emitted.starts_with("# [cfg (not (test))] impl Guarded"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_override_where() -> Result<()> {
+        let overridden: DeriveInput = syn::parse_str(
+            "#[getter(override_where = \"T: Clone\")] struct O<T> where T: Copy { a: T }"
+        )?;
+        let emitted = NamedStruct::try_from(&overridden)?.emit().to_string();
+        assert!(emitted.starts_with("impl < T > O < T > where T : Clone {"));
+
+        let removed: DeriveInput = syn::parse_str(
+            "#[getter(override_where = \"\")] struct R<T> where T: Copy { a: T }"
+        )?;
+        let emitted = NamedStruct::try_from(&removed)?.emit().to_string();
+        assert!(emitted.starts_with("impl < T > R < T > {"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_bounds() -> Result<()> {
+        let a: Action = syn::parse_str("bound = \"T: Hash, U: Eq\"")?;
+        assert!(a == Action::Bound(vec![syn::parse_str("T: Hash")?, syn::parse_str("U: Eq")?]));
+
+        let r: Result<Action> = syn::parse_str("bound = \"T Hash\"");
+        assert!(r.is_err());
+
+        let cache: DeriveInput = syn::parse_str(
+            "struct Cache<T> where T: Clone { \
+             #[getter(bound = \"T: Hash\")] items: Vec<T>, \
+             #[getter(bound = \"T: Ord\")] sorted: Vec<T>, \
+             #[getter(bound = \"T: Hash\")] keys: Vec<T>, \
+             len: usize }"
+        )?;
+        let emitted = NamedStruct::try_from(&cache)?.emit().to_string();
+        assert!(emitted.starts_with("impl < T > Cache < T > where T : Clone { pub fn len"));
+        assert!(emitted.contains(
+            "impl < T > Cache < T > where T : Clone , T : Hash { pub fn items"
+        ));
+        assert!(emitted.contains("pub fn keys"));
+        assert!(emitted.contains("impl < T > Cache < T > where T : Clone , T : Ord { pub fn sorted"));
+        assert!(emitted.matches("impl").count() == 3);
+
+        let unbounded: DeriveInput = syn::parse_str(
+            "struct U<T> { #[getter(bound = \"T: Hash\")] a: T }"
+        )?;
+        let emitted = NamedStruct::try_from(&unbounded)?.emit().to_string();
+        assert!(emitted.contains("impl < T > U < T > where T : Hash { pub fn a"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_constructor() -> Result<()> {
+        let a: StructAction = syn::parse_str("new")?;
+        assert!(a == StructAction::New(Ident::new("new", Span::call_site())));
+
+        let built: DeriveInput = syn::parse_str(
+            "#[getter(new)] struct Built<T> { a: T, #[getter(skip)] b: u64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&built)?.emit().to_string();
+        assert!(emitted.contains("pub const fn new (a : T , b : u64) -> Self { Self { a , b } }"));
+
+        let scoped: DeriveInput = syn::parse_str(
+            "#[getter(new, vis = \"pub(crate)\")] struct Scoped { a: u64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&scoped)?.emit().to_string();
+        assert!(emitted.contains("pub (crate) const fn new (a : u64)"));
+
+        let colliding: DeriveInput = syn::parse_str(
+            "#[getter(new)] struct Colliding { new: bool }"
+        )?;
+        assert!(NamedStruct::try_from(&colliding).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_default_fn() -> Result<()> {
+        let a: StructAction = syn::parse_str("default_fn")?;
+        assert!(a == StructAction::DefaultFn(Ident::new("with_defaults", Span::call_site())));
+
+        let defaulted: DeriveInput = syn::parse_str(
+            "#[getter(default_fn)] struct Defaulted { a: u64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&defaulted)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub fn with_defaults () -> Self where Self : :: core :: default :: Default"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_snapshot() -> Result<()> {
+        let a: StructAction = syn::parse_str("snapshot = \"StateSnapshot\"")?;
+        let check = StructAction::Snapshot(
+            Ident::new("snapshot", Span::call_site()),
+            Box::new(syn::parse_str("StateSnapshot")?),
+        );
+        assert!(a == check);
+
+        let state: DeriveInput = syn::parse_str(
+            "#[getter(snapshot = \"Snap<T>\")] struct State<T> { a: T, #[getter(skip)] b: u64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&state)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub fn snapshot (& self) -> Snap < T > { Snap :: < T > { \
+             a : :: core :: clone :: Clone :: clone (& self . a) , \
+             b : :: core :: clone :: Clone :: clone (& self . b) } }"
+        ));
+
+        let colliding: DeriveInput = syn::parse_str(
+            "#[getter(snapshot = \"Snap\")] struct State { snapshot: u64 }"
+        )?;
+        assert!(NamedStruct::try_from(&colliding).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_iter_fields() -> Result<()> {
+        let a: StructAction = syn::parse_str("iter_fields")?;
+        assert!(a == StructAction::IterFields);
+
+        let point: DeriveInput = syn::parse_str(
+            "#[getter(iter_fields)] struct Point { x: f64, y: f64, #[getter(skip)] z: f64 }"
+        )?;
+        let emitted = NamedStruct::try_from(&point)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub fn iter_fields (& self) -> impl :: core :: iter :: Iterator < Item = & f64 > + '_ \
+             { :: core :: iter :: IntoIterator :: into_iter ([& self . x , & self . y , & self . z]) }"
+        ));
+
+        let mixed: DeriveInput = syn::parse_str(
+            "#[getter(iter_fields)] struct Mixed { x: f64, y: f32 }"
+        )?;
+        let error = NamedStruct::try_from(&mixed).err().unwrap();
+        assert!(error.to_string() == "iter_fields needs every field to be of type `f64`");
+
+        let empty: DeriveInput = syn::parse_str("#[getter(iter_fields)] struct Empty {}")?;
+        assert!(NamedStruct::try_from(&empty).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_ordered() -> Result<()> {
+        let a: Action = syn::parse_str("order = 2")?;
+        assert!(a == Action::Order(syn::parse_str("2")?));
+
+        let r: Result<Action> = syn::parse_str("order = \"2\"");
+        assert!(r.is_err());
+
+        let ordered: DeriveInput = syn::parse_str(
+            "struct S { a: u8, #[getter(order = 5)] b: u8, #[getter(skip)] c: u8, d: u8, \
+             #[getter(order = 1)] e: u8 }"
+        )?;
+        let emitted = NamedStruct::try_from(&ordered)?.emit().to_string();
+        let at = |name: &str| emitted.find(format!("pub fn {} (", name).as_str()).unwrap();
+        assert!(at("e") < at("b") && at("b") < at("a") && at("a") < at("d"));
+
+        let duplicate: DeriveInput = syn::parse_str(
+            "struct S { #[getter(order = 1)] a: u8, #[getter(order = 1)] b: u8 }"
+        )?;
+        let error = NamedStruct::try_from(&duplicate).err().unwrap();
+        assert!(error.to_string() == "more than one getter is given `order = 1`");
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_merge_bounds() -> Result<()> {
+        let a: StructAction = syn::parse_str("merge_bounds")?;
+        assert!(a == StructAction::MergeBounds);
+
+        let merged: DeriveInput = syn::parse_str(
+            "#[getter(merge_bounds)] struct S<T, U> where T: Clone { \
+             #[getter(bound = \"T: Hash\")] a: T, #[getter(bound = \"U: Eq, T: Hash\")] b: U, \
+             #[getter(bound = \"T: Clone\")] c: T, d: u8 }"
+        )?;
+        let emitted = NamedStruct::try_from(&merged)?.emit().to_string();
+        assert!(emitted.starts_with(
+            "impl < T , U > S < T , U > where T : Clone , T : Hash , U : Eq {"
+        ));
+        assert!(emitted.matches("impl").count() == 1);
+
+        let separate: DeriveInput = syn::parse_str(
+            "struct S<T, U> { #[getter(bound = \"T: Hash\")] a: T, #[getter(bound = \"U: Eq\")] b: U }"
+        )?;
+        let emitted = NamedStruct::try_from(&separate)?.emit().to_string();
+        assert!(emitted.matches("impl").count() == 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_by_value_self() -> Result<()> {
+        let a: StructAction = syn::parse_str("by_value_self")?;
+        assert!(a == StructAction::ByValueSelf);
+
+        let point: DeriveInput = syn::parse_str(
+            "#[getter(by_value_self)] struct Point { x: i64, #[getter(clone)] label: &'static str }"
+        )?;
+        let emitted = NamedStruct::try_from(&point)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub fn x (self) -> i64 where Self : :: core :: marker :: Copy { self . x }"
+        ));
+        assert!(emitted.contains("pub fn label (& self) -> & 'static str {"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_positional_names() -> Result<()> {
+        let point: DeriveInput = syn::parse_str(
+            "#[getter(positional_names = [\"x\", \"y\"])] struct Point(i64, #[getter(copy)] u8);"
+        )?;
+        let emitted = NamedStruct::try_from(&point)?.emit().to_string();
+        assert!(emitted.contains("pub fn x (& self) -> & i64 { & self . 0 }"));
+        assert!(emitted.contains("pub fn y (& self) -> u8 { self . 1 }"));
+
+        let short: DeriveInput = syn::parse_str(
+            "#[getter(positional_names = [\"x\"])] struct Point(i64, i64);"
+        )?;
+        let error = NamedStruct::try_from(&short).err().unwrap();
+        assert!(error.to_string() == "positional_names has 1 names for 2 fields");
+
+        let invalid: DeriveInput = syn::parse_str(
+            "#[getter(positional_names = [\"x\", \"1y\"])] struct Point(i64, i64);"
+        )?;
+        assert!(NamedStruct::try_from(&invalid).is_err());
+
+        let named: DeriveInput = syn::parse_str(
+            "#[getter(positional_names = [\"x\"])] struct Point { a: i64 }"
+        )?;
+        assert!(NamedStruct::try_from(&named).is_err());
+
+        let grouped: DeriveInput = syn::parse_str(
+            "#[getter(positional_names = [\"x\"], new)] struct Point(i64);"
+        )?;
+        assert!(NamedStruct::try_from(&grouped).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_field_names() -> Result<()> {
+        let a: StructAction = syn::parse_str("field_names")?;
+        assert!(a == StructAction::FieldNames { all: false });
+
+        let a: StructAction = syn::parse_str("field_names = \"all\"")?;
+        assert!(a == StructAction::FieldNames { all: true });
+
+        let r: Result<StructAction> = syn::parse_str("field_names = \"some\"");
+        assert!(r.is_err());
+
+        let getters: DeriveInput = syn::parse_str(
+            "#[getter(field_names, prefix = \"get_\")] \
+             struct S { a: u8, #[getter(skip)] b: u16, r#type: u32 }"
+        )?;
+        let emitted = NamedStruct::try_from(&getters)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub const fn field_names () -> & 'static [& 'static str] { & [\"a\" , \"type\"] }"
+        ));
+
+        let every: DeriveInput = syn::parse_str(
+            "#[getter(field_names = \"all\")] struct S { a: u8, #[getter(skip)] b: u16 }"
+        )?;
+        let emitted = NamedStruct::try_from(&every)?.emit().to_string();
+        assert!(emitted.contains("{ & [\"a\" , \"b\"] }"));
+
+        let colliding: DeriveInput = syn::parse_str(
+            "#[getter(field_names)] struct S { field_names: u8 }"
+        )?;
+        assert!(NamedStruct::try_from(&colliding).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_lifetime_bounds() -> Result<()> {
+        let bounded: DeriveInput = syn::parse_str(
+            "#[getter(free_fn)] struct S<'a, 'b: 'a> { x: &'a str, y: &'b str }"
+        )?;
+        let emitted = NamedStruct::try_from(&bounded)?.emit().to_string();
+        assert!(emitted.starts_with("impl < 'a , 'b : 'a > S < 'a , 'b > {"));
+        assert!(emitted.contains("pub fn s_x < 'getter , 'a , 'b : 'a >"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_unsized_generics() -> Result<()> {
+        let boxed: DeriveInput = syn::parse_str(
+            "#[getter(free_fn)] struct W<T: ?Sized> where T: Send { inner: Box<T> }"
+        )?;
+        let emitted = NamedStruct::try_from(&boxed)?.emit().to_string();
+        assert!(emitted.starts_with("impl < T : ? Sized > W < T > where T : Send {"));
+        assert!(emitted.contains(
+            "pub fn w_inner < 'getter , T : ? Sized > (s : & 'getter W < T >)"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_qualified_path() -> Result<()> {
+        let projected: DeriveInput = syn::parse_str(
+            "struct P<T: IntoIterator> { #[getter(clone)] a: <T as IntoIterator>::IntoIter, \
+             b: Option<<T as IntoIterator>::Item> }"
+        )?;
+        let emitted = NamedStruct::try_from(&projected)?.emit().to_string();
+        assert!(emitted.contains(
+            "pub fn a (& self) -> < T as IntoIterator > :: IntoIter"
+        ));
+        assert!(emitted.contains(
+            "pub fn b (& self) -> & Option < < T as IntoIterator > :: Item >"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn emit_const_where_clause() -> Result<()> {
+        let sized: DeriveInput = syn::parse_str(
+            "struct Sized<const N: usize> where [(); N]: Sized { a: [u8; N] }"
+        )?;
+        let emitted = NamedStruct::try_from(&sized)?.emit().to_string();
+        assert!(emitted.starts_with(
+            "impl < const N : usize > Sized < N > where [() ; N] : Sized"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn slice_element_of() -> Result<()> {
+        let ty: Type = syn::parse_str("[u8; 16]")?;
+        let elem: Type = syn::parse_str("u8")?;
+        assert!(slice_element(&ty) == Some(&elem));
+
+        let ty: Type = syn::parse_str("Vec<String>")?;
+        let elem: Type = syn::parse_str("String")?;
+        assert!(slice_element(&ty) == Some(&elem));
+
+        let ty: Type = syn::parse_str("Box<[String]>")?;
+        assert!(slice_element(&ty) == Some(&elem));
+
+        let ty: Type = syn::parse_str("Box<String>")?;
+        assert!(slice_element(&ty).is_none());
+
+        let ty: Type = syn::parse_str("Vec<Vec<f64>>")?;
+        let elem: Type = syn::parse_str("Vec<f64>")?;
+        assert!(slice_element(&ty) == Some(&elem));
+
+        let ty: Type = syn::parse_str("Vec<std::collections::HashMap<String, Vec<u8>>>")?;
+        let elem: Type = syn::parse_str("std::collections::HashMap<String, Vec<u8>>")?;
+        assert!(slice_element(&ty) == Some(&elem));
+
+        let ty: Type = syn::parse_str("Vec<>")?;
+        assert!(slice_element(&ty).is_none());
+
+        let ty: Type = syn::parse_str("Option<String>")?;
+        assert!(slice_element(&ty).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn owned_target_of() -> Result<()> {
+        let ty: Type = syn::parse_str("&'a str")?;
+        let owned: Type = syn::parse_str("::std::string::String")?;
+        assert!(owned_target(&ty) == Some(owned));
+
+        let ty: Type = syn::parse_str("&'a [u8]")?;
+        let owned: Type = syn::parse_str("::std::vec::Vec<u8>")?;
+        assert!(owned_target(&ty) == Some(owned));
+
+        let ty: Type = syn::parse_str("&std::path::Path")?;
+        let owned: Type = syn::parse_str("::std::path::PathBuf")?;
+        assert!(owned_target(&ty) == Some(owned));
+
+        let ty: Type = syn::parse_str("&'a mut str")?;
+        assert!(owned_target(&ty).is_none());
+
+        let ty: Type = syn::parse_str("&'a u64")?;
+        assert!(owned_target(&ty).is_none());
+
+        let ty: Type = syn::parse_str("String")?;
+        assert!(owned_target(&ty).is_none());
+
+        let ty: Type = syn::parse_str("Vec<String>")?;
+        let item: Type = syn::parse_str("&String")?;
+        assert!(iter_item(&ty) == Some(item));
+
+        let ty: Type = syn::parse_str("std::collections::HashMap<String, u32>")?;
+        let item: Type = syn::parse_str("(&String, &u32)")?;
+        assert!(iter_item(&ty) == Some(item));
+
+        let ty: Type = syn::parse_str("Option<String>")?;
+        assert!(iter_item(&ty).is_none());
+
+        let ty: Type = syn::parse_str("std::cell::Cell<u32>")?;
+        let inner: Type = syn::parse_str("u32")?;
+        assert!(wrapped(&ty, "Cell") == Some(&inner));
+        assert!(wrapped(&ty, "RefCell").is_none());
 
         Ok(())
     }
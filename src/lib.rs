@@ -1,10 +1,32 @@
-//! This library provides two derive macros. One, `Getters` for autogenerating getters and
-//! `Dissolve` for consuming a struct returning a tuple of all fields. They can only be
-//! used on named structs.
+//! This library provides five derive macros. `Getters` for autogenerating getters and
+//! `Dissolve` for consuming a struct returning a tuple of all fields, with
+//! `GettersDissolve` deriving both at once. `GettersOwned` for getters returning `Copy`
+//! fields by value, and `AsMut` for lending out the field of a single-field struct.
 //!
 //! # Derives
 //!
-//! Only named structs can derive `Getters` or `Dissolve`.
+//! Only named structs can derive `Getters`. `Dissolve` can also be derived on tuple
+//! structs.
+//!
+//! An enum of a single struct-like variant can derive `Getters` too, as if it were a
+//! struct of the variant's fields. Each getter matches the field out of the one variant.
+//! Options building or borrowing the struct as a whole, `new`, `snapshot`, `group`,
+//! `flatten`, `iter_fields` and `by_value_self`, are not allowed on it.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! enum Message {
+//!     Text {
+//!         body: String,
+//!         #[getter(copy)]
+//!         id: u32,
+//!     },
+//! }
+//!
+//! let message = Message::Text { body: "hello".to_string(), id: 3 };
+//! assert!(message.body() == "hello" && message.id() == 3);
+//! ```
 //!
 //! # `Getter` methods generated
 //!
@@ -12,6 +34,8 @@
 //! publicly visible. The methods return an immutable reference to the struct field of the
 //! same name. If there is already a method defined with that name there'll be a collision.
 //! In these cases one of two attributes can be set to either `skip` or `rename` the getter.
+//! Should two generated getters end up with the same name, the derive fails pointing at
+//! both of them.
 //! 
 //!
 //! # `Getters` Usage
@@ -26,10 +50,8 @@
 //!     num: u64,    
 //! }
 //! 
-//! fn main() {
-//!     let number = Number { num: 655 };
-//!     assert!(number.num() == &655);
-//! }
+//! let number = Number { num: 655 };
+//! assert!(number.num() == &655);
 //! ```
 //!
 //! Here, a method called `num()` has been created for the `Number` struct which gives a
@@ -44,8 +66,6 @@
 //!     gen_t: T,
 //!     gen_u: U,
 //! }
-//! #
-//! # fn main() { }
 //! ```
 //!
 //! The macro can also handle generic types with trait bounds. For example;
@@ -56,8 +76,6 @@
 //!     gen_t: T,
 //!     gen_u: U,
 //! }
-//! #
-//! # fn main() { }
 //! ```
 //! The trait bounds can also be declared in a `where` clause.
 //!
@@ -70,18 +88,404 @@
 //!     comp: &'b str,
 //!     num: u64,
 //! }
-//! #
-//! # fn main() { }
 //! ```
 //!
 //! # `Getter` Attributes
 //! Getters can be further configured to either skip or rename a getter.
 //!
 //! * #[getter(skip)]
-//! Will skip generating a getter for the field being decorated.
+//!   Will skip generating a getter for the field being decorated. A reason can be given
+//!   too, `#[getter(skip = "internal cache")]`, which is listed in the docs of the
+//!   generated `impl` block.
+//!
+//! * #[getter(keep)]
+//!   Fields of the unit type `()` get no getter by default, as `&()` is of no use. This
+//!   generates one anyway, as does giving the field a `rename`.
 //!
 //! * #[getter(rename = "name")]
-//! Changes the name of the getter (default is the field name) to "name".
+//!   Changes the name of the getter (default is the field name) to "name". The name may
+//!   also be given as a bare identifier, `#[getter(rename = name)]`.
+//!
+//! * #[getter(alias = "name")]
+//!   Also generates a method "name" returning the same as the getter, eg; `colour()` next
+//!   to `color()`. It can be given more than once, and a second `rename` on the same field
+//!   adds an alias too.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! struct Paint {
+//!     #[getter(rename = "color")]
+//!     #[getter(rename = "colour")]
+//!     shade: u32,
+//! }
+//!
+//! let paint = Paint { shade: 0xff0000 };
+//! assert!(std::ptr::eq(paint.color(), paint.colour()));
+//! ```
+//!
+//! * #[getter(rename_from_type)]
+//!   Names the getter after the field's type instead, its last path segment in snake case,
+//!   eg; `config()` for a field of type `crate::Config` or `&'a Config`. Two getters of the
+//!   same name fail to compile, as with `rename`.
+//!
+//! * #[getter(copy)]
+//!   The getter returns a copy of the field, which must be `Copy`.
+//!
+//! * #[getter(clone)]
+//!   The getter returns a clone of the field, which must be `Clone`.
+//!
+//! * #[getter(as_ref)]
+//!   For an `Option<T>` field the getter returns `Option<&T>`.
+//!
+//! * #[getter(to_owned)]
+//!   For a borrowed `&str`, `&[T]`, `&Path`, `&OsStr` or `&CStr` field the getter returns
+//!   an owned `String`, `Vec<T>`, `PathBuf`, `OsString` or `CString` made by `ToOwned`.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! struct Borrowed<'a> {
+//!     #[getter(to_owned)]
+//!     name: &'a str,
+//!     #[getter(to_owned)]
+//!     bytes: &'a [u8],
+//! }
+//!
+//! let borrowed = Borrowed { name: "ferris", bytes: &[1, 2] };
+//! let name: String = borrowed.name();
+//! let bytes: Vec<u8> = borrowed.bytes();
+//! assert!(name == "ferris" && bytes == vec![1, 2]);
+//! ```
+//!
+//! * #[getter(pin_ref)]
+//!   For a `Pin<Box<T>>` field the getter returns `&T` through `as_ref().get_ref()`. A
+//!   shared reference can't be used to move the value, so `T` needn't be `Unpin`.
+//!
+//! * #[getter(once)]
+//!   For a lazily initialised `OnceCell<T>` or `OnceLock<T>` field the getter returns
+//!   `Option<&T>` through `get()`, `None` until the cell has been set.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! use std::cell::OnceCell;
+//!
+//! #[derive(Getters)]
+//! struct Lazy {
+//!     #[getter(once)]
+//!     greeting: OnceCell<String>,
+//! }
+//!
+//! let lazy = Lazy { greeting: OnceCell::new() };
+//! assert!(lazy.greeting().is_none());
+//! lazy.greeting.set("hello".to_owned()).unwrap();
+//! assert!(lazy.greeting().map(String::as_str) == Some("hello"));
+//! ```
+//!
+//! * #[getter(upgrade)]
+//!   For a `Weak<T>` field the getter upgrades it, returning `Option<Arc<T>>`, or
+//!   `Option<Rc<T>>` for a field whose type is written with an `rc` path such as
+//!   `rc::Weak<T>`. Use `#[getter(upgrade = "rc")]` or `#[getter(upgrade = "arc")]` to say
+//!   which when the type is written as a bare `Weak<T>` imported from `std::rc`.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! use std::sync::{Arc, Weak};
+//!
+//! #[derive(Getters)]
+//! struct Child {
+//!     #[getter(upgrade)]
+//!     parent: Weak<u32>,
+//! }
+//!
+//! let parent = Arc::new(7);
+//! let child = Child { parent: Arc::downgrade(&parent) };
+//! assert!(child.parent().as_deref() == Some(&7));
+//! drop(parent);
+//! assert!(child.parent().is_none());
+//! ```
+//!
+//! * #[getter(ref)]
+//!   The getter returns a reference to the field. This is the default, but can be given to
+//!   override a struct level `all`.
+//!
+//! * #[getter(as_slice)]
+//!   For an array `[T; N]`, `Vec<T>` or `Box<[T]>` field the getter returns a slice `&[T]`
+//!   instead. Only the outer type is sliced, so a `Vec<Vec<T>>` field returns `&[Vec<T>]`.
+//!
+//! * #[getter(cell_get)]
+//!   For a `Cell<T>` field where `T: Copy` the getter returns the contained `T` by value.
+//!
+//! * #[getter(try_into = "Type")]
+//!   The getter clones the field and converts it with `TryFrom`, returning
+//!   `Result<Type, <Type as TryFrom<Field>>::Error>`.
+//!
+//! * #[getter(as_dyn = "dyn Trait")]
+//!   The getter returns the field as a trait object reference, eg; `&dyn Handler`, by
+//!   unsized coercion. The field's type must implement the trait.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! trait Handler {
+//!     fn handle(&self) -> u8;
+//! }
+//!
+//! struct Echo;
+//!
+//! impl Handler for Echo {
+//!     fn handle(&self) -> u8 { 1 }
+//! }
+//!
+//! #[derive(Getters)]
+//! struct Server {
+//!     #[getter(as_dyn = "dyn Handler")]
+//!     handler: Echo,
+//! }
+//!
+//! let server = Server { handler: Echo };
+//! let handler: &dyn Handler = server.handler();
+//! assert!(handler.handle() == 1);
+//! ```
+//!
+//! * #[getter(deref = "Type")]
+//!   For a field whose type implements `Deref<Target = Type>`, eg; a newtype, the getter
+//!   returns `&Type` through `&*`. The target has to be given, as it can't be seen from
+//!   the field's type.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! use std::ops::Deref;
+//!
+//! struct Name(String);
+//!
+//! impl Deref for Name {
+//!     type Target = String;
+//!
+//!     fn deref(&self) -> &String { &self.0 }
+//! }
+//!
+//! #[derive(Getters)]
+//! struct User {
+//!     #[getter(deref = "String")]
+//!     name: Name,
+//! }
+//!
+//! let user = User { name: Name("ferris".to_owned()) };
+//! assert!(user.name() == "ferris");
+//! ```
+//!
+//! * #[getter(lock)]
+//!   For a `Mutex<T>` field the getter locks the mutex returning a `MutexGuard<'_, T>`. It
+//!   panics if the mutex is poisoned. Use `#[getter(lock = "try")]` instead to call
+//!   `try_lock`, returning a `TryLockResult<MutexGuard<'_, T>>`.
+//!
+//! * #[getter(manually_drop)]
+//!   For a `ManuallyDrop<T>` field the getter returns `&T`.
+//!
+//! * #[getter(unwrap)]
+//!   For an `Option<T>` or `Result<T, E>` field that is always `Some` or `Ok` by some
+//!   invariant, the getter returns `&T` unwrapping the field. It panics otherwise, noted in a
+//!   generated `# Panics` doc section. A `Result` needs `E: Debug`.
+//!
+//! * #[getter(ptr)]
+//!   The getter returns a raw pointer `*const T` to the field, for handing to FFI. Making
+//!   the pointer is safe, dereferencing it is `unsafe` as usual.
+//!
+//! * #[getter(ptr_mut)]
+//!   As `ptr` but takes `&mut self` returning a `*mut T`.
+//!
+//! * #[getter(const_len)]
+//!   For an array `[T; N]` field also generates an associated `const fn <getter>_len() ->
+//!   usize` returning `N`, usable in const contexts.
+//!
+//! * #[getter(result_split)]
+//!   For a `Result<T, E>` field also generates `<getter>_ok(&self) -> Option<&T>` and
+//!   `<getter>_err(&self) -> Option<&E>`, next to the usual getter.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! struct Job {
+//!     #[getter(result_split)]
+//!     outcome: Result<u32, String>,
+//! }
+//!
+//! let job = Job { outcome: Err("timeout".to_owned()) };
+//! assert!(job.outcome_ok().is_none());
+//! assert!(job.outcome_err().map(String::as_str) == Some("timeout"));
+//! ```
+//!
+//! * #[getter(array_full)]
+//!   For an array `[T; N]` field with a literal length, the getter returns `&[T]` as with
+//!   `as_slice`, and a getter `<getter>_<i>(&self) -> &T` is generated for each index, eg;
+//!   `rgb()`, `rgb_0()`, `rgb_1()` and `rgb_2()` for `rgb: [u8; 3]`.
+//!
+//! * #[getter(map_access)]
+//!   For a `HashMap<K, V>` or `BTreeMap<K, V>` field also generates
+//!   `<getter>_get(&self, key: &K) -> Option<&V>` and `<getter>_contains(&self, key: &K) ->
+//!   bool`, next to the usual getter.
+//!
+//! * #[getter(bound = "T: Hash")]
+//!   Only generates the getter when the bounds hold, by putting it in an `impl` block of
+//!   its own whose `where` clause adds them. Getters with the same bounds share a block.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! use std::hash::Hash;
+//!
+//! #[derive(Getters)]
+//! struct Cache<T> {
+//!     #[getter(bound = "T: Hash")]
+//!     items: Vec<T>,
+//!     hits: usize,
+//! }
+//!
+//! let cache = Cache { items: vec![1u8], hits: 0 };
+//! assert!(cache.items() == &vec![1] && *cache.hits() == 0);
+//! ```
+//!
+//! * #[getter(order = N)]
+//!   Places the getter in the generated impl by `N` rather than by declaration, which only
+//!   matters when reading expanded code or docs. Getters given an order come first, sorted
+//!   by it, then the rest as declared. Two getters can't share an order. Getters with a
+//!   `bound` are still sorted, within their own `impl` block.
+//!
+//! * #[getter(doc_alias = "alias")]
+//!   Adds `#[doc(alias = "alias")]` to the getter so rustdoc search finds it under the
+//!   alias too. It can be given more than once.
+//!
+//! * #[getter(iter)]
+//!   Generates an additional `<getter>_iter()` method returning an iterator over the
+//!   field. A `Vec<T>` yields `&T` and a `HashMap<K, V>` or `BTreeMap<K, V>` yields
+//!   `(&K, &V)`.
+//!
+//! * #[getter(indexed)]
+//!   Generates an additional `<getter>_at(index)` method returning `Option<&T>` for the
+//!   element at `index` of an array, `Vec<T>`, `Box<[T]>` or `&[T]` field, `None` when out
+//!   of bounds.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! struct Queue {
+//!     #[getter(indexed)]
+//!     jobs: Vec<String>,
+//! }
+//!
+//! let queue = Queue { jobs: vec!["build".to_owned()] };
+//! assert!(queue.jobs_at(0).map(String::as_str) == Some("build"));
+//! assert!(queue.jobs_at(1).is_none());
+//! ```
+//!
+//! * #[getter(eq)]
+//!   Generates an additional `<getter>_eq(&self, other: &Q) -> bool` method comparing the
+//!   field with `other`, for any `Q` the field's type is `PartialEq` with, eg;
+//!   `name_eq("ferris")` for a `String` field.
+//!
+//! * #[getter(replace)]
+//!   Generates an additional `replace_<getter>(&mut self, value: T) -> T` method swapping
+//!   in `value` and returning the old value, as `std::mem::replace` does. It's named after
+//!   the getter, so follows `rename`.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! struct Session {
+//!     #[getter(replace)]
+//!     token: String,
+//! }
+//!
+//! let mut session = Session { token: "old".to_owned() };
+//! assert!(session.replace_token("new".to_owned()) == "old");
+//! assert!(session.token() == "new");
+//! ```
+//!
+//! * #[getter(take)]
+//!   Generates an additional `take_<getter>(&mut self) -> T` method moving the value out.
+//!   An `Option` field is left `None` by `Option::take`, any other field is replaced with
+//!   its `Default` by `std::mem::take`, so needs to be `Default`.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! struct Batch {
+//!     #[getter(take)]
+//!     pending: Vec<u32>,
+//!     #[getter(take)]
+//!     error: Option<String>,
+//! }
+//!
+//! let mut batch = Batch { pending: vec![1, 2], error: Some("full".to_owned()) };
+//! assert!(batch.take_pending() == vec![1, 2] && batch.pending().is_empty());
+//! assert!(batch.take_error().is_some() && batch.error().is_none());
+//! ```
+//!
+//! * #[getter(consume)]
+//!   Generates an additional `into_<getter>(self) -> T` method consuming the struct and
+//!   returning the field, dropping the rest. As it moves the field out, it can't be used on
+//!   a struct implementing `Drop`.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! struct Response {
+//!     status: u16,
+//!     #[getter(consume)]
+//!     body: Vec<u8>,
+//! }
+//!
+//! let response = Response { status: 200, body: vec![1, 2] };
+//! assert!(*response.status() == 200);
+//! assert!(response.into_body() == vec![1, 2]);
+//! ```
+//!
+//! * #[getter(gat_lifetime)]
+//!   Names the borrow of `self`, generating `fn name<'getter>(&'getter self) -> &'getter T
+//!   where Self: 'getter`, so the getter can implement a trait method of that shape, eg;
+//!   one returning a generic associated type. The lifetime is `'getter`, or `'getter_`
+//!   if the struct has a `'getter` of its own. A reference field, `&'a T`, is still
+//!   returned as `&'a T`.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! trait Source {
+//!     type Item<'a> where Self: 'a;
+//!     fn item<'a>(&'a self) -> Self::Item<'a> where Self: 'a;
+//! }
+//!
+//! #[derive(Getters)]
+//! struct Lines {
+//!     #[getter(gat_lifetime)]
+//!     text: String,
+//! }
+//!
+//! impl Source for Lines {
+//!     type Item<'a> = &'a String;
+//!     fn item<'a>(&'a self) -> Self::Item<'a> where Self: 'a {
+//!         self.text()
+//!     }
+//! }
+//!
+//! let lines = Lines { text: "a\nb".to_owned() };
+//! assert!(lines.item().lines().count() == 2);
+//! ```
+//!
+//! * #[getter(hash)]
+//!   Generates an additional `<getter>_hash(&self) -> u64` method hashing the field with
+//!   `std::collections::hash_map::DefaultHasher`, eg; for a cache key. The field must be
+//!   `Hash`. Hashes are stable within a run of a program but not across Rust releases, so
+//!   shouldn't be persisted.
+//!
+//! * #[getter(map = "method", map_ty = "Type")]
+//!   Generates an additional `<getter>_mapped()` method calling the given method, which
+//!   takes no arguments, on the field and returning its `Type` result. Eg; `#[getter(map =
+//!   "to_uppercase", map_ty = "String")]` on a `name: String` field generates
+//!   `fn name_mapped(&self) -> String { self.name.to_uppercase() }`.
+//!
+//! * #[getter(body = "expression", ty = "Type")]
+//!   Replaces the getter body with the given expression returning `Type`. This is an escape
+//!   hatch for anything the other options don't cover. It composes with `rename` but not
+//!   with the other options changing the getter.
 //!
 //!```edition2018
 //! # use derive_getters::Getters;
@@ -94,9 +498,387 @@
 //!
 //!     #[getter(rename = "number")]
 //!     rename_me: u64,
+//!
+//!     #[getter(as_slice)]
+//!     bytes: [u8; 4],
+//!
+//!     #[getter(cell_get)]
+//!     counter: std::cell::Cell<u32>,
+//!
+//!     #[getter(try_into = "std::num::NonZeroU64")]
+//!     count: u64,
+//!
+//!     #[getter(body = "self.label.trim()", ty = "&str")]
+//!     label: String,
+//!
+//!     #[getter(lock)]
+//!     shared: std::sync::Mutex<Vec<u64>>,
+//!
+//!     #[getter(iter)]
+//!     names: Vec<String>,
+//! }
+//! ```
+//!
+//! # `Getter` Struct Attributes
+//! Struct level attributes add getters spanning several fields.
+//!
+//! * #[getter(group(name, field, ...))]
+//!   Generates a getter called "name" returning a tuple of references to the listed fields,
+//!   in the order listed. Every listed field must exist, even if its own getter is skipped.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! #[getter(group(point, x, y))]
+//! struct Location {
+//!     x: i64,
+//!     y: i64,
+//!     label: String,
+//! }
+//!
+//! let location = Location { x: 1, y: 2, label: "home".to_owned() };
+//! assert!(location.point() == (&1, &2));
+//! ```
+//!
+//! * #[getter(flatten(field: [name: Type, ...]))]
+//!   Generates getters on the struct for fields of the struct held in `field`, each
+//!   returning `&Type`, eg; `fn a(&self) -> &u32 { &self.common.a }`. The derive can't see
+//!   inside the inner struct so its fields have to be listed with their types. The
+//!   flattened getters must not share a name with any other getter.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! struct Common {
+//!     id: u32,
+//!     name: String,
+//! }
+//!
+//! #[derive(Getters)]
+//! #[getter(flatten(common: [id: u32, name: String]))]
+//! struct Outer {
+//!     #[getter(skip)]
+//!     common: Common,
+//!     extra: u32,
+//! }
+//!
+//! let common = Common { id: 1, name: "first".to_owned() };
+//! let outer = Outer { common, extra: 2 };
+//! assert!(*outer.id() + *outer.extra() == 3);
+//! assert!(outer.name() == "first");
+//! ```
+//!
+//! * #[getter(expr(name = "expression", ty = "Type"))]
+//!   Generates a getter called "name" returning `Type` whose body is the given expression.
+//!   The expression can refer to the struct through `self`.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! #[getter(expr(area = "self.width * self.height", ty = "u64"))]
+//! struct Rectangle {
+//!     width: u64,
+//!     height: u64,
+//! }
+//!
+//! let rectangle = Rectangle { width: 3, height: 4 };
+//! assert!(rectangle.area() == 12);
+//! ```
+//!
+//! * #[getter(all = "mode")]
+//!   Sets the default for every field getter, one of `"ref"`, `"copy"`, `"clone"` or
+//!   `"as_ref"`. Fields given an option changing their getter, including `ref`, use that
+//!   instead.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! #[getter(all = "copy")]
+//! struct Pixel {
+//!     x: u32,
+//!     y: u32,
+//!     #[getter(ref)]
+//!     label: String,
+//! }
+//!
+//! let pixel = Pixel { x: 1, y: 2, label: "origin".to_owned() };
+//! assert!(pixel.x() + pixel.y() == 3);
+//! assert!(pixel.label() == "origin");
+//! ```
+//!
+//! * #[getter(doc_examples)]
+//!   Attaches an `# Examples` section to the docs of every field getter, showing it
+//!   called on an instance of the struct, eg; `let num = number.num();`. The example is
+//!   marked `ignore` so it isn't run as a doctest.
+//!
+//! * #[getter(cfg = "predicate")]
+//!   Wraps the generated `impl` block in `#[cfg(predicate)]`, eg; `#[getter(cfg =
+//!   "not(doctest)")]`. Given more than once, every predicate must hold.
+//!
+//! * #[getter(free_fn)]
+//!   Also generates a free function for every field getter, named after the struct and the
+//!   getter, eg; `point_x(s: &Point) -> &i64` for the `x` getter of `Point`. They call the
+//!   getter so return the same thing.
+//!
+//! * #[getter(new)]
+//!   Also generates a constructor `const fn new(...) -> Self` taking every field in the
+//!   order declared, including fields whose getter is skipped. It has the same visibility
+//!   as the getters, set by `vis`.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! #[getter(new)]
+//! struct Pair<T> {
+//!     left: T,
+//!     right: T,
+//! }
+//!
+//! let pair = Pair::new(1, 2);
+//! assert!(*pair.left() + *pair.right() == 3);
+//! ```
+//!
+//! * #[getter(default_fn)]
+//!   Also generates `fn with_defaults() -> Self where Self: Default`, building the struct
+//!   from its `Default` implementation. It has the same visibility as the getters.
+//!
+//! * #[getter(snapshot = "Snapshot")]
+//!   Also generates `fn snapshot(&self) -> Snapshot`, cloning every field, skipped or not,
+//!   into the named struct. It must be defined separately with the same field names, and
+//!   every field must be `Clone`.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! struct CounterSnapshot {
+//!     hits: u64,
+//!     label: String,
+//! }
+//!
+//! #[derive(Getters)]
+//! #[getter(snapshot = "CounterSnapshot")]
+//! struct Counter {
+//!     hits: u64,
+//!     label: String,
+//! }
+//!
+//! let counter = Counter { hits: 3, label: "requests".to_owned() };
+//! let snapshot = counter.snapshot();
+//! assert!(snapshot.hits == 3 && snapshot.label == "requests");
+//! ```
+//!
+//! * #[getter(iter_fields)]
+//!   For a struct whose fields all have the same type `T`, also generates
+//!   `fn iter_fields(&self) -> impl Iterator<Item = &T>` yielding every field, skipped or
+//!   not, in declaration order. Types are compared as written, so fields of differing types
+//!   fail to compile.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! #[getter(iter_fields)]
+//! struct Rgb {
+//!     r: u8,
+//!     g: u8,
+//!     b: u8,
+//! }
+//!
+//! let rgb = Rgb { r: 1, g: 2, b: 3 };
+//! assert!(rgb.iter_fields().sum::<u8>() == 6);
+//! ```
+//!
+//! * #[getter(by_value_self)]
+//!   For a `Copy` struct, getters take `self` by value and return a copy of the field, eg;
+//!   `fn x(self) -> i64`. Each getter is bound by `where Self: Copy`, so a struct that isn't
+//!   `Copy` fails to compile. Fields given another mode, such as `clone`, still borrow `self`.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Clone, Copy, Getters)]
+//! #[getter(by_value_self)]
+//! struct Point {
+//!     x: i64,
+//!     y: i64,
+//! }
+//!
+//! let point = Point { x: 1, y: 2 };
+//! assert!(point.x() + point.y() == 3);
+//! ```
+//!
+//! * #[getter(positional_names = ["x", "y"])]
+//!   Names the getters of a tuple struct, one name per field in order, which otherwise
+//!   can't derive `Getters`. Each name must be a valid identifier. Field options such as
+//!   `skip` and `copy` work as usual, but `group`, `flatten`, `new`, `snapshot` and
+//!   `iter_fields` are only for named structs.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! #[getter(positional_names = ["x", "y"])]
+//! struct Point(i64, #[getter(copy)] i64);
+//!
+//! let point = Point(1, 2);
+//! assert!(*point.x() == 1 && point.y() == 2);
+//! ```
+//!
+//! * #[getter(eq_all)]
+//!   As `#[getter(eq)]` on every field with a getter. A field whose type isn't `PartialEq`
+//!   still gets the helper, it just can't be called.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! #[getter(eq_all)]
+//! struct Point {
+//!     x: i32,
+//!     label: String,
+//! }
+//!
+//! let point = Point { x: 1, label: "origin".to_owned() };
+//! assert!(point.x_eq(&1) && point.label_eq("origin"));
+//! ```
+//!
+//! * #[getter(field_names)]
+//!   Also generates `const fn field_names() -> &'static [&'static str]`, naming the fields
+//!   that have a getter in declaration order. Skipped fields are left out, unless given as
+//!   `#[getter(field_names = "all")]` to name every field.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! #[getter(field_names)]
+//! struct Login {
+//!     user: String,
+//!     #[getter(skip)]
+//!     password: String,
+//! }
+//!
+//! assert!(Login::field_names() == ["user"]);
+//! ```
+//!
+//! * #[getter(merge_bounds)]
+//!   Rather than an `impl` block for each set of field `bound`s, adds every field's bounds
+//!   to the one `impl` block's `where` clause, along with the struct's own. A predicate
+//!   given more than once is only added once. Every getter then needs all the bounds.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! use std::{fmt::Debug, hash::Hash};
+//!
+//! #[derive(Getters)]
+//! #[getter(merge_bounds)]
+//! struct Index<K, V> {
+//!     #[getter(bound = "K: Hash")]
+//!     keys: Vec<K>,
+//!     #[getter(bound = "V: Debug, K: Hash")]
+//!     values: Vec<V>,
+//! }
+//!
+//! let index = Index { keys: vec!["a"], values: vec![1] };
+//! assert!(index.keys().len() == index.values().len());
+//! ```
+//!
+//! * #[getter(override_where = "T: Clone")]
+//!   Replaces the `where` clause of the struct with the one given on the generated getters,
+//!   rather than adding to it. Bounds declared inline with the generic parameters are kept.
+//!   The given clause must still imply the struct's own `where` clause, which the compiler
+//!   requires of any `impl` for it.
+//!
+//! * #[getter(vis = "pub(crate)")]
+//!   Sets the visibility of every generated getter, `pub` by default.
+//!
+//! * #[getter(prefix = "get_")]
+//!   Prepends the prefix to the name of every field getter, eg; `get_x()` for a field `x`.
+//!
+//! * #[getter(rename_all = "camelCase")]
+//!   Changes the case of every field getter name, one of `"snake_case"`, `"lowercase"`,
+//!   `"UPPERCASE"`, `"camelCase"`, `"PascalCase"` or `"SCREAMING_SNAKE_CASE"`. Any prefix
+//!   is added afterwards. Neither option touches getters given their own name by `rename`.
+//!   A template with a single `{}` placeholder for the field name can be given instead,
+//!   eg; `#[getter(rename_all = "template:{}_value")]` turns a field `foo` into `foo_value()`.
+//!
+//! * #[getter(strip_prefix = "user_")]
+//!   Removes the prefix from the name of every field getter that starts with it, eg;
+//!   `name()` for a field `user_name`, before `rename_all` and `prefix` apply. The result
+//!   must still be a valid name, and two getters of the same name fail to compile.
+//!
+//! Struct options can be combined in one attribute separated by commas.
+//!
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! #[getter(vis = "pub(crate)", prefix = "get_", rename_all = "snake_case")]
+//! struct Person {
+//!     first_name: String,
+//!     #[getter(rename = "surname")]
+//!     last_name: String,
+//! }
+//!
+//! let person = Person { first_name: "Ada".to_owned(), last_name: "Lovelace".to_owned() };
+//! assert!(person.get_first_name() == "Ada");
+//! assert!(person.surname() == "Lovelace");
+//! ```
+//!
+//! * #[getter(strict)]
+//!   Rejects attributes on the struct or its fields that misspell `getter`, eg;
+//!   `#[getters(skip)]`, `#[get_ter(skip)]` or `#[Getter(skip)]`, pointing at the likely
+//!   typo. Other crates' attributes, such as `#[setter(skip)]`, are left alone.
+//!
+//! * #[getter(keep_empty_impl)]
+//!   When every field is skipped no `impl` block is generated at all. This option forces an
+//!   empty `impl` block to be generated regardless.
+//!
+//! # `GettersOwned` methods generated
+//!
+//! For structs made up of `Copy` values, `GettersOwned` generates getters returning each
+//! field by value instead of by reference. Every field must be `Copy`, otherwise the
+//! derive fails to compile pointing at the offending field type. It takes no attributes.
+//!
+//! ```edition2018
+//! # use derive_getters::GettersOwned;
+//! #[derive(GettersOwned)]
+//! struct Point {
+//!     x: i64,
+//!     y: i64,
 //! }
-//! #
-//! # fn main() { }
+//!
+//! let point = Point { x: 3, y: 4 };
+//! assert!(point.x() + point.y() == 7);
+//! ```
+//!
+//! # `AsMut` implemented
+//!
+//! For a newtype or other struct of a single field, named or positional, `AsMut` derives
+//! `AsMut<Inner>` returning `&mut` the field. Generics are carried over. A struct of more
+//! or fewer fields fails to compile. It takes no attributes.
+//!
+//! ```edition2018
+//! # use derive_getters::AsMut;
+//! #[derive(AsMut)]
+//! struct Meters(f64);
+//!
+//! let mut distance = Meters(1.5);
+//! *distance.as_mut() += 1.0;
+//! assert!(distance.0 == 2.5);
+//! ```
+//!
+//! # `GettersDissolve` derived
+//!
+//! Deriving `GettersDissolve` is the same as deriving both `Getters` and `Dissolve`, with
+//! the `getter` and `dissolve` attributes of each.
+//!
+//! ```edition2018
+//! # use derive_getters::GettersDissolve;
+//! #[derive(GettersDissolve)]
+//! #[dissolve(rename = "split")]
+//! struct Pair {
+//!     #[getter(copy)]
+//!     left: u8,
+//!     right: String,
+//! }
+//!
+//! let pair = Pair { left: 1, right: "two".to_string() };
+//! assert!(pair.left() == 1 && pair.right() == "two");
+//! let (left, right) = pair.split();
+//! assert!(left == 1 && right == "two");
 //! ```
 //!
 //! # `Dissolve` method generated
@@ -104,6 +886,7 @@
 //! Deriving `Dissolve` on a named struct will generate a method `dissolve(self)` which
 //! shall return a tuple of all struct fields in the order they were defined. Calling this
 //! method consumes the struct. The name of this method can be changed with an attribute.
+//! A struct with a single field dissolves into a one-tuple `(T,)`.
 //!
 //! # `Dissolve` usage
 //!
@@ -116,24 +899,30 @@
 //!     count: usize,
 //! }
 //! 
-//! fn main() {
-//!     let stuff = Stuff {
-//!         name: "Hogie".to_owned(),
-//!         price: 123.4f64,
-//!         count: 100,
-//!     };
+//! let stuff = Stuff {
+//!     name: "Hogie".to_owned(),
+//!     price: 123.4f64,
+//!     count: 100,
+//! };
 //!
-//!     let (n, p, c) = stuff.dissolve();
-//!     assert!(n == "Hogie");
-//!     assert!(p == 123.4f64);
-//!     assert!(c == 100);
-//! }
+//! let (n, p, c) = stuff.dissolve();
+//! assert!(n == "Hogie");
+//! assert!(p == 123.4f64);
+//! assert!(c == 100);
 //! ```
 //!
+//! The same goes for a tuple struct, returning its fields in position order.
+//!
 //! # `Dissolve` Attributes
-//! You can rename the `dissolve` function by using a struct attribute.
+//! You can rename the `dissolve` function by using a struct attribute. Several options
+//! can be given in one attribute separated by commas, eg; `#[dissolve(rename = "shatter",
+//! alias)]`.
 //!
 //! * #[dissolve(rename = "name")]
+//!   The name may also be given as a bare identifier, `#[dissolve(rename = name)]`. Raw
+//!   identifiers such as `"r#move"` can be used to name the method after a keyword. The
+//!   method can't share its name with a field, as a getter for that field would collide
+//!   with it, so a struct with a field named `dissolve` has to rename the method.
 //!
 //! ```edition2018
 //! # use derive_getters::Dissolve;
@@ -144,16 +933,318 @@
 //!     b: i64,
 //!     c: f64,
 //! }
-//! #
-//! # fn main() { }
 //! ```
 //!
+//! * #[dissolve(alias)]
+//!   When renamed, also keep a `dissolve` method which simply calls the renamed one, so
+//!   existing callers of `dissolve()` don't break. Without `rename` it's an error.
+//!
+//! ```edition2018
+//! # use derive_getters::Dissolve;
+//! #[derive(Dissolve)]
+//! #[dissolve(rename = "shatter")]
+//! #[dissolve(alias)]
+//! struct Glass {
+//!     shards: u64,
+//!     sharp: bool,
+//! }
+//!
+//! let (shards, sharp) = Glass { shards: 3, sharp: true }.dissolve();
+//! assert!(shards == 3 && sharp);
+//! ```
+//!
+//! * #[dissolve(from_impl)]
+//!   Also implement `From<Struct>` for the dissolved tuple so the conversion can be done
+//!   with `.into()` or used in generic bounds. This is allowed by the orphan rule since
+//!   the struct is local to the crate deriving it.
+//!
+//! ```edition2018
+//! # use derive_getters::Dissolve;
+//! #[derive(Dissolve)]
+//! #[dissolve(from_impl)]
+//! struct Point {
+//!     x: i64,
+//!     y: i64,
+//! }
+//!
+//! let (x, y): (i64, i64) = Point { x: 1, y: 2 }.into();
+//! assert!(x == 1 && y == 2);
+//! ```
+//!
+//! * #[dissolve(with)]
+//!   Also generate `dissolve_with` (or `<name>_with` when renamed) which consumes the
+//!   struct and passes every field to a closure in place of building a tuple.
+//!
+//! ```edition2018
+//! # use derive_getters::Dissolve;
+//! #[derive(Dissolve)]
+//! #[dissolve(with)]
+//! struct Order {
+//!     price: f64,
+//!     count: usize,
+//! }
+//!
+//! let order = Order { price: 2.5, count: 4 };
+//! let total = order.dissolve_with(|price, count| price * count as f64);
+//! assert!(total == 10.0);
+//! ```
+//!
+//! * #[dissolve(reconstruct)]
+//!   Also generate the inverse associated function, `reconstruct(tuple) -> Self`, which
+//!   builds the struct back from a tuple in the same order `dissolve` returns.
+//!
+//! ```edition2018
+//! # use derive_getters::Dissolve;
+//! #[derive(Dissolve)]
+//! #[dissolve(reconstruct)]
+//! struct Pair {
+//!     left: u8,
+//!     right: u8,
+//! }
+//!
+//! let pair = Pair::reconstruct(Pair { left: 1, right: 2 }.dissolve());
+//! assert!(pair.left == 1 && pair.right == 2);
+//! ```
+//!
+//! * #[dissolve(try_from)]
+//!   Also implement `From<tuple>` for the struct, the trait form of `reconstruct`. The
+//!   standard library's blanket impl then provides `TryFrom<tuple>` as well, with an
+//!   `Infallible` error, so the struct can be built with either `.into()` or `.try_into()`.
+//!
+//! ```edition2018
+//! # use derive_getters::Dissolve;
+//! use std::convert::TryInto;
+//!
+//! #[derive(Dissolve)]
+//! #[dissolve(try_from)]
+//! struct Pair {
+//!     left: u8,
+//!     right: u8,
+//! }
+//!
+//! let pair: Pair = (1, 2).into();
+//! assert!(pair.left == 1 && pair.right == 2);
+//! let pair: Pair = (3, 4).try_into().unwrap();
+//! assert!(pair.left == 3 && pair.right == 4);
+//! ```
+//!
+//! * #[dissolve(into_parts(field, ...))]
+//!   Also generate `into_parts(self)` splitting the struct into two tuples. The first holds
+//!   the listed fields in the order given, the second the remaining fields in declared order.
+//!   Given more than once, the lists are joined. Listing a field twice is an error.
+//!
+//! ```edition2018
+//! # use derive_getters::Dissolve;
+//! #[derive(Dissolve)]
+//! #[dissolve(into_parts(id, name))]
+//! struct User {
+//!     name: String,
+//!     email: String,
+//!     id: u64,
+//!     admin: bool,
+//! }
+//!
+//! let user = User {
+//!     name: "ferris".to_owned(),
+//!     email: "ferris@example.com".to_owned(),
+//!     id: 1,
+//!     admin: false,
+//! };
+//! let ((id, name), (email, admin)) = user.into_parts();
+//! assert!(id == 1 && name == "ferris");
+//! assert!(email == "ferris@example.com" && !admin);
+//! ```
+//!
+//! * #[dissolve(by_ref)]
+//!   Also generate `dissolve_ref(&self)` (or `<name>_ref` when renamed) returning a tuple of
+//!   references to all fields without consuming the struct. Add `explicit_lifetime` to name
+//!   the borrow, `fn dissolve_ref<'dissolve>(&'dissolve self) -> (&'dissolve A, ...)`, or
+//!   `'dissolve_` if the struct has a `'dissolve` of its own. Without `by_ref` or `mut`
+//!   it's an error.
+//!
+//! ```edition2018
+//! # use derive_getters::Dissolve;
+//! #[derive(Dissolve)]
+//! #[dissolve(by_ref, explicit_lifetime)]
+//! struct Borrowed<'a> {
+//!     name: &'a str,
+//!     count: usize,
+//! }
+//!
+//! let borrowed = Borrowed { name: "ferris", count: 2 };
+//! let (name, count) = borrowed.dissolve_ref();
+//! assert!(*name == "ferris" && *count == 2);
+//! ```
+//!
+//! * #[dissolve(mut)]
+//!   Also generate `dissolve_mut(&mut self)` (or `<name>_mut` when renamed) returning a tuple
+//!   of mutable references to all fields, for editing several of them at once. The borrow of
+//!   `self` is split across the fields, so each reference can be used independently.
+//!   `explicit_lifetime` names this borrow too.
+//!
+//! ```edition2018
+//! # use derive_getters::Dissolve;
+//! #[derive(Dissolve)]
+//! #[dissolve(mut)]
+//! struct Counter {
+//!     count: u32,
+//!     label: String,
+//! }
+//!
+//! let mut counter = Counter { count: 1, label: "one".to_string() };
+//! let (count, label) = counter.dissolve_mut();
+//! *count += 1;
+//! label.push_str(" more");
+//! assert!(counter.dissolve() == (2, "one more".to_string()));
+//! ```
+//!
+//! * #[dissolve(stable_arity = N)]
+//!   Asserts at compile time that the dissolved tuple has `N` elements. As the tuple grows
+//!   or shrinks with the struct's fields, code destructuring it would otherwise only break
+//!   where it's used; this fails the build at the attribute instead, as a reminder to
+//!   update `N` and its callers.
+//!
+//! * #[dissolve(group_tail(n))]
+//!   Nests the last `n` dissolved fields in a tuple of their own, eg; `(A, B, (C, D))` with
+//!   `group_tail(2)`, to pass the tail on whole. `n` must be at least 1 and no more than
+//!   the fields dissolved; nesting all of them gives a one-tuple, `((A, B),)`. As with
+//!   `as_options` only the dissolve method, its alias and `from_impl` are nested, and
+//!   `stable_arity` counts the nested tuple as one element.
+//!
+//! ```edition2018
+//! # use derive_getters::Dissolve;
+//! #[derive(Dissolve)]
+//! #[dissolve(group_tail(2))]
+//! struct Request {
+//!     method: &'static str,
+//!     path: String,
+//!     headers: Vec<String>,
+//!     body: Vec<u8>,
+//! }
+//!
+//! let request = Request {
+//!     method: "GET",
+//!     path: "/".to_owned(),
+//!     headers: Vec::new(),
+//!     body: Vec::new(),
+//! };
+//! let (method, _path, payload) = request.dissolve();
+//! assert!(method == "GET" && payload.0.is_empty() && payload.1.is_empty());
+//! ```
+//!
+//! * #[dissolve(drop_first = [field, ...])]
+//!   Skipped fields are normally dropped along with the rest of the struct once the
+//!   dissolve method returns. Listing them here drops them, in the order given, before the
+//!   tuple is built instead, eg; to release a lock guard first. Only skipped fields can be
+//!   listed, by name or by index for a tuple struct.
+//!
+//! * #[dissolve(as_options)]
+//!   Wraps each element of the dissolved tuple in `Some`, returning `(Option<A>,
+//!   Option<B>)`, so parts can be taken out with `Option::take` one stage at a time. This
+//!   applies to the dissolve method, its alias and `from_impl`; `reconstruct` and
+//!   `try_from` still take the plain tuple.
+//!
+//! ```edition2018
+//! # use derive_getters::Dissolve;
+//! #[derive(Dissolve)]
+//! #[dissolve(as_options)]
+//! struct Staged {
+//!     socket: u16,
+//!     buffer: Vec<u8>,
+//! }
+//!
+//! let (mut socket, buffer) = Staged { socket: 80, buffer: vec![1] }.dissolve();
+//! assert!(socket.take() == Some(80) && socket.is_none());
+//! assert!(buffer == Some(vec![1]));
+//! ```
+//!
+//! * #[dissolve(into_variant = "State::Done")]
+//!   Dissolves into an enum variant rather than a tuple, eg; for a state machine, returning
+//!   `State::Done { a, b }` from named fields or `State::Done(field_0)` from a tuple
+//!   struct. The macro can't see the variant, so if its shape differs from the struct's
+//!   add `tuple` or `struct`, eg; `#[dissolve(into_variant = "State::Done", tuple)]`. The
+//!   path must name the enum, with any generics as a turbofish, `State::<T>::Done`. This
+//!   applies to the dissolve method, its alias and `from_impl`, and can't be combined with
+//!   `as_options`.
+//!
+//! ```edition2018
+//! # use derive_getters::Dissolve;
+//! enum State {
+//!     Running,
+//!     Done { code: i32, output: String },
+//! }
+//!
+//! #[derive(Dissolve)]
+//! #[dissolve(into_variant = "State::Done")]
+//! struct Finished {
+//!     code: i32,
+//!     output: String,
+//! }
+//!
+//! let state = Finished { code: 0, output: "ok".to_owned() }.dissolve();
+//! assert!(matches!(state, State::Done { code: 0, .. }));
+//! # let _ = State::Running;
+//! ```
+//!
+//! * #[dissolve(vis = "pub(crate)")]
+//!   Sets the visibility of the dissolve method and the other generated methods, `pub` by
+//!   default. An empty string, `#[dissolve(vis = "")]`, makes them private.
+//!
+//! * #[dissolve(trace)]
+//!   With the `tracing` feature enabled, the dissolve method emits a
+//!   `tracing::trace!("dissolving {}", ...)` event naming the struct before consuming it.
+//!   The crate using the derive must then depend on `tracing`. Without the feature, the
+//!   attribute is accepted and does nothing.
+//!
+//! # `Dissolve` Field Attributes
+//!
+//! * #[dissolve(skip)]
+//!   Leaves the field out of the returned tuple, for both named and tuple structs. The
+//!   kept fields stay in their declared order. This can't be combined with `reconstruct`
+//!   or `try_from`.
+//!
+//! ```edition2018
+//! # use derive_getters::Dissolve;
+//! #[derive(Dissolve)]
+//! struct Triple(u8, #[dissolve(skip)] String, u32);
+//!
+//! let (first, last) = Triple(1, "gone".to_owned(), 3).dissolve();
+//! assert!(first == 1 && last == 3);
+//! ```
+//!
+//! # Other Attributes
+//!
+//! Only `getter` and `dissolve` attributes are read, so others such as `#[repr(C)]` or
+//! `#[repr(transparent)]` on the struct or its fields have no effect on the derives. The
+//! exception is `#[deprecated]` on a field, which is copied with its `since` and `note`
+//! onto the field's getter, aliases and other generated methods, so their callers are
+//! warned too. Code the derives generate around the field, such as `new`, `snapshot`,
+//! free functions or the `Dissolve` impls, allows the deprecation instead.
+//!
+//! # `#[non_exhaustive]`
+//!
+//! Both derives work on `#[non_exhaustive]` structs. Bear in mind that adding a field
+//! still changes the tuple returned by `dissolve`.
+//!
 //! # Panics
 //!
-//! If `Getters` or `Dissolve` are derived on unit or unnamed structs, enums or unions.
+//! If `Getters` is derived on unit or unnamed structs, enums of other than one struct-like
+//! variant or unions. Likewise for
+//! `Dissolve` except on unnamed structs.
 //!
-//! # Cannot Do
-//! Const generics aren't handled by this macro nor are they tested.
+//! Errors are normally reported as `compile_error!` at the offending tokens. With the
+//! `panic-on-error` feature the derive panics with the error message instead, giving a
+//! backtrace into the derive when debugging it. It's not meant for everyday use.
+//!
+//! # `no_std`
+//! Generated code only names `core`, so the derives work in `no_std` crates, except for
+//! options whose code needs `std`: `lock`, `hash`, `upgrade` and `to_owned`, along with
+//! `#[dissolve(trace)]` and anything a `body` or `expr` names. In a `no_std` crate those
+//! getter options fail to compile at the field they're given on, unable to find `std`.
+//!
+//! # Const Generics
+//! Const generic parameters and `where` clauses using them, such as `where [(); N]: Sized`,
+//! are carried over to the generated `impl` unchanged.
 use std::convert::TryFrom;
 
 extern crate proc_macro;
@@ -162,8 +1253,20 @@ use syn::{DeriveInput, parse_macro_input};
 mod faultmsg;
 mod dissolve;
 mod getters;
+mod owned;
+mod as_mut;
 mod extract;
 
+/// How a derive reports an error, as `compile_error!` tokens or, with the
+/// `panic-on-error` feature, by panicking.
+fn failed(err: syn::Error) -> proc_macro2::TokenStream {
+    if cfg!(feature = "panic-on-error") {
+        panic!("{}", err);
+    }
+
+    err.to_compile_error()
+}
+
 /// Generate getter methods for all named struct fields in a seperate struct `impl` block.
 /// Getter methods share the name of the field they're 'getting'. Methods return an
 /// immutable reference to the field.
@@ -173,7 +1276,31 @@ pub fn getters(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     
     getters::NamedStruct::try_from(&ast)
         .map(|ns| ns.emit())
-        .unwrap_or_else(|err| err.to_compile_error())
+        .unwrap_or_else(failed)
+        .into()
+}
+
+/// Generate by-value getter methods for all named struct fields. Every field must be
+/// `Copy`.
+#[proc_macro_derive(GettersOwned)]
+pub fn getters_owned(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    owned::NamedStruct::try_from(&ast)
+        .map(|ns| ns.emit())
+        .unwrap_or_else(failed)
+        .into()
+}
+
+/// Implement `AsMut` for a struct of one field, named or positional, lending out that
+/// field mutably.
+#[proc_macro_derive(AsMut)]
+pub fn as_mut(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    as_mut::Wrapper::try_from(&ast)
+        .map(|wrapper| wrapper.emit())
+        .unwrap_or_else(failed)
         .into()
 }
 
@@ -185,6 +1312,37 @@ pub fn dissolve(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 
     dissolve::NamedStruct::try_from(&ast)
         .map(|ns| ns.emit())
-        .unwrap_or_else(|err| err.to_compile_error())
+        .unwrap_or_else(failed)
         .into()
 }
+
+/// Both `Getters` and `Dissolve` in one derive, taking `getter` and `dissolve` attributes.
+/// Should both fail, both errors are reported.
+#[proc_macro_derive(GettersDissolve, attributes(getter, dissolve))]
+pub fn getters_dissolve(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    let getters = getters::NamedStruct::try_from(&ast).map(|ns| ns.emit());
+    let dissolve = dissolve::NamedStruct::try_from(&ast).map(|ns| ns.emit());
+    match (getters, dissolve) {
+        (Ok(getters), Ok(dissolve)) => quote::quote!(#getters #dissolve),
+        (Err(mut err), Err(other)) => {
+            err.combine(other);
+            failed(err)
+        },
+        (Err(err), _) | (_, Err(err)) => failed(err),
+    }.into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(feature = "panic-on-error", should_panic(expected = "unsupported"))]
+    fn failed_reports() {
+        let error = syn::Error::new(proc_macro2::Span::call_site(), "unsupported");
+        let emitted = failed(error).to_string();
+        assert!(emitted.contains("compile_error"));
+    }
+}
@@ -0,0 +1,127 @@
+//! GettersOwned internals
+use std::convert::TryFrom;
+
+use proc_macro2::{TokenStream, Span};
+use quote::{quote, quote_spanned};
+use syn::{
+    DeriveInput,
+    FieldsNamed,
+    Type,
+    Ident,
+    Result,
+    Error,
+    spanned::Spanned,
+};
+
+use crate::{
+    extract::{any_deprecated, named_fields, named_struct},
+    faultmsg::Problem,
+};
+
+pub struct Field {
+    ty: Type,
+    name: Ident,
+}
+
+impl Field {
+    fn from_field(field: &syn::Field) -> Result<Self> {
+        let name: Ident = field.ident
+            .clone()
+            .ok_or(Error::new(Span::call_site(), Problem::UnnamedField))?;
+
+        Ok(Field {
+            ty: field.ty.clone(),
+            name,
+        })
+    }
+
+    fn from_fields_named(fields_named: &FieldsNamed) -> Result<Vec<Self>> {
+        fields_named.named
+            .iter()
+            .map(Field::from_field)
+            .collect()
+    }
+
+    /// The field is copied out through a `Copy` bound spanned on the field type, so a
+    /// non-`Copy` field points the compiler error at that type.
+    fn emit(&self) -> TokenStream {
+        let returns = &self.ty;
+        let field_name = &self.name;
+        let copied = quote_spanned!(returns.span()=>
+            fn copied<T: ::core::marker::Copy>(value: &T) -> T { *value }
+            copied(&self.#field_name)
+        );
+
+        quote!(
+            pub fn #field_name(&self) -> #returns {
+                #copied
+            }
+        )
+    }
+}
+
+pub struct NamedStruct<'a> {
+    original: &'a DeriveInput,
+    name: Ident,
+    fields: Vec<Field>,
+    /// Any field is `#[deprecated]`, so the getters allow its use.
+    deprecated: bool,
+}
+
+impl<'a> NamedStruct<'a> {
+    pub fn emit(&self) -> TokenStream {
+        let (impl_generics, struct_generics, where_clause) = self.original.generics
+            .split_for_impl();
+        let struct_name = &self.name;
+        let methods: Vec<TokenStream> = self.fields
+            .iter()
+            .map(|field| field.emit())
+            .collect();
+        let allow = if self.deprecated {
+            quote!(#[allow(deprecated)])
+        } else {
+            TokenStream::new()
+        };
+
+        quote!(
+            #allow
+            impl #impl_generics #struct_name #struct_generics
+                #where_clause
+            {
+                #(#methods)*
+            }
+        )
+    }
+}
+
+impl<'a> TryFrom<&'a DeriveInput> for NamedStruct<'a> {
+    type Error = Error;
+
+    fn try_from(node: &'a DeriveInput) -> Result<Self> {
+        let struct_data = named_struct(node)?;
+        let named_fields = named_fields(&struct_data.fields)?;
+        let fields = Field::from_fields_named(named_fields)?;
+
+        Ok(NamedStruct {
+            original: node,
+            name: node.ident.clone(),
+            fields,
+            deprecated: any_deprecated(&struct_data.fields),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn emit_by_value() -> Result<()> {
+        let point: DeriveInput = syn::parse_str("struct Point { x: i64, y: i64 }")?;
+        let emitted = NamedStruct::try_from(&point)?.emit().to_string();
+        assert!(emitted.contains("pub fn x (& self) -> i64"));
+        assert!(emitted.contains("copied (& self . y)"));
+
+        Ok(())
+    }
+}
@@ -38,7 +38,7 @@ impl LotsOfStuff {
 
 fn main() {
     let n = Number { num: 64 };
-    let number = n.dissolve();
+    let (number,) = n.dissolve();
     assert!(number == 64);
 
     let inner = Inner { a: 22, b: -33 };
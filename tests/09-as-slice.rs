@@ -0,0 +1,56 @@
+//! Slice getters over arrays and vectors.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Arrays {
+    #[getter(as_slice)]
+    bytes: [u8; 16],
+
+    #[getter(as_slice)]
+    numbers: [i32; 3],
+
+    #[getter(as_slice)]
+    #[getter(rename = "items")]
+    list: Vec<String>,
+
+    whole: [u8; 2],
+
+    #[getter(as_slice)]
+    boxed_bytes: Box<[u8]>,
+
+    #[getter(as_slice)]
+    boxed_strings: Box<[String]>,
+}
+
+fn main() {
+    let arrays = Arrays {
+        bytes: [7; 16],
+        numbers: [1, -2, 3],
+        list: vec!["one".to_owned(), "two".to_owned()],
+        whole: [0, 1],
+        boxed_bytes: vec![1, 2, 3].into_boxed_slice(),
+        boxed_strings: vec!["boxed".to_owned()].into_boxed_slice(),
+    };
+
+    let bytes: &[u8] = arrays.bytes();
+    assert!(bytes.len() == 16);
+    assert!(bytes.iter().all(|b| *b == 7));
+
+    let numbers: &[i32] = arrays.numbers();
+    assert!(numbers == &[1, -2, 3]);
+
+    let items: &[String] = arrays.items();
+    assert!(items.len() == 2);
+    assert!(items[1] == "two");
+
+    let whole: &[u8; 2] = arrays.whole();
+    assert!(whole == &[0, 1]);
+
+    let boxed_bytes: &[u8] = arrays.boxed_bytes();
+    assert!(boxed_bytes == &[1, 2, 3]);
+
+    let boxed_strings: &[String] = arrays.boxed_strings();
+    assert!(boxed_strings.len() == 1);
+    assert!(boxed_strings[0] == "boxed");
+}
@@ -0,0 +1,9 @@
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Typo {
+    #[getter(renmae = "number")]
+    field: u64,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+//! The iter_fields option needs every field to be of the same type.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(iter_fields)]
+struct Mixed {
+    x: f64,
+    y: f64,
+    label: String,
+}
+
+fn main() {}
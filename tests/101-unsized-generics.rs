@@ -0,0 +1,42 @@
+//! Generic parameters relaxed with `?Sized` keep the relaxation on the generated impls.
+
+use std::fmt::Debug;
+
+use derive_getters::{Dissolve, Getters};
+
+#[derive(Getters, Dissolve)]
+#[getter(free_fn)]
+struct Boxed<T: ?Sized> {
+    inner: Box<T>,
+    count: usize,
+}
+
+#[derive(Getters)]
+struct Tail<T>
+where
+    T: ?Sized + Debug,
+{
+    len: usize,
+    #[getter(bound = "T: AsRef<[u8]>")]
+    tail: T,
+}
+
+fn main() {
+    let boxed: Boxed<str> = Boxed { inner: "unsized".into(), count: 1 };
+    let inner: &Box<str> = boxed.inner();
+    assert!(&**inner == "unsized");
+    assert!(&**boxed_inner(&boxed) == "unsized");
+    assert!(*boxed.count() == 1);
+
+    let slice: Boxed<[u8]> = Boxed { inner: vec![1, 2].into_boxed_slice(), count: 2 };
+    let (inner, count) = slice.dissolve();
+    assert!(*inner == [1, 2] && count == 2);
+
+    let dynamic: Boxed<dyn Debug> = Boxed { inner: Box::new(3), count: 3 };
+    assert!(format!("{:?}", dynamic.inner()) == "3");
+
+    // The last field of `Tail` is unsized once coerced.
+    let tail: &Tail<[u8]> = &Tail { len: 2, tail: [4, 5] };
+    assert!(*tail.len() == 2);
+    assert!(tail.tail() == [4, 5]);
+}
@@ -0,0 +1,29 @@
+//! Getters on a tuple struct named by positional_names.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(positional_names = ["x", "y", "z"])]
+struct Point(
+    #[getter(copy)]
+    f64,
+    #[getter(copy)]
+    f64,
+    #[getter(skip)]
+    f64,
+);
+
+#[derive(Getters)]
+#[getter(positional_names = ["name", "tags"], prefix = "get_")]
+struct Labelled<T>(String, Vec<T>);
+
+fn main() {
+    let point = Point(1.0, 2.0, 3.0);
+    assert!(point.x() == 1.0);
+    assert!(point.y() == 2.0);
+    assert!(point.2 == 3.0);
+
+    let labelled = Labelled("crate".to_owned(), vec![1u8, 2]);
+    assert!(labelled.get_name() == "crate");
+    assert!(labelled.get_tags().len() == 2);
+}
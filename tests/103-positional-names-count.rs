@@ -0,0 +1,9 @@
+//! positional_names needs one name for every field.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(positional_names = ["x", "y"])]
+struct Point(f64, f64, f64);
+
+fn main() {}
@@ -0,0 +1,35 @@
+//! Getters taking self by value on a Copy struct.
+
+use derive_getters::Getters;
+
+#[derive(Clone, Copy, Getters)]
+#[getter(by_value_self, free_fn)]
+struct Point {
+    x: i64,
+    y: i64,
+    label: &'static str,
+}
+
+#[derive(Clone, Copy, Getters)]
+#[getter(by_value_self)]
+struct Pair<T> {
+    #[getter(rename = "left")]
+    #[getter(rename = "first")]
+    a: T,
+    b: T,
+}
+
+fn sum<F: Fn(Point) -> i64>(points: &[Point], f: F) -> i64 {
+    points.iter().copied().map(f).sum()
+}
+
+fn main() {
+    let point = Point { x: 1, y: 2, label: "origin" };
+    assert!(point.x() == 1);
+    assert!(point_y(point) == 2);
+    assert!(point.label() == "origin");
+    assert!(sum(&[point, point], Point::x) == 2);
+
+    let pair = Pair { a: 1u8, b: 2u8 };
+    assert!(pair.left() == 1 && pair.first() == 1 && pair.b() == 2);
+}
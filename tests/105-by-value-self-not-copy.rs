@@ -0,0 +1,12 @@
+//! by_value_self needs the struct to be Copy.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(by_value_self)]
+struct Point {
+    x: i64,
+    y: i64,
+}
+
+fn main() {}
@@ -0,0 +1,66 @@
+//! Dissolving into enum variants of either shape.
+
+use derive_getters::Dissolve;
+
+#[derive(Debug, PartialEq)]
+enum State<T> {
+    Idle,
+    Running(u32, T),
+    Done { code: i32, output: String },
+}
+
+#[derive(Dissolve)]
+#[dissolve(into_variant = "State::<T>::Done", from_impl)]
+struct Finished<T> {
+    code: i32,
+    output: String,
+    #[dissolve(skip)]
+    _marker: std::marker::PhantomData<T>,
+}
+
+#[derive(Dissolve)]
+#[dissolve(into_variant = "State::<T>::Running", tuple)]
+struct Progress<T> {
+    step: u32,
+    payload: T,
+}
+
+#[derive(Dissolve)]
+#[dissolve(into_variant = "State::<T>::Running", rename = "resume", alias)]
+struct Paused<T>(u32, T);
+
+#[derive(Dissolve)]
+#[dissolve(into_variant = "Shape::Square", struct)]
+struct Square {
+    side: u8,
+}
+
+enum Shape {
+    Square { side: u8 },
+}
+
+fn main() {
+    let done = Finished::<u8> {
+        code: 0,
+        output: "ok".to_owned(),
+        _marker: std::marker::PhantomData,
+    };
+    assert!(done.dissolve() == State::Done { code: 0, output: "ok".to_owned() });
+
+    let done: State<u8> = Finished {
+        code: 1,
+        output: String::new(),
+        _marker: std::marker::PhantomData,
+    }.into();
+    assert!(matches!(done, State::Done { code: 1, .. }));
+
+    let running = Progress { step: 3, payload: "chunk" }.dissolve();
+    assert!(running == State::Running(3, "chunk"));
+
+    let resumed = Paused(4, 'x').dissolve();
+    assert!(resumed == State::Running(4, 'x'));
+    assert!(Paused(5, 'y').resume() != State::Idle);
+
+    let Shape::Square { side } = Square { side: 2 }.dissolve();
+    assert!(side == 2);
+}
@@ -0,0 +1,16 @@
+//! into_variant needs the path to name the enum as well as the variant.
+
+use derive_getters::Dissolve;
+
+#[allow(dead_code)]
+enum State {
+    Done { code: i32 },
+}
+
+#[derive(Dissolve)]
+#[dissolve(into_variant = "Done")]
+struct Finished {
+    code: i32,
+}
+
+fn main() {}
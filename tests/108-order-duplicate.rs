@@ -0,0 +1,13 @@
+//! Two getters can't be given the same order.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Report {
+    #[getter(order = 0)]
+    title: String,
+    #[getter(order = 0)]
+    body: String,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+//! Replacing a field returns the old value.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Connection<T> {
+    #[getter(replace)]
+    host: String,
+    #[getter(replace)]
+    #[getter(rename = "attempts")]
+    retries: u8,
+    #[getter(replace)]
+    #[getter(skip)]
+    state: T,
+}
+
+fn main() {
+    let mut connection = Connection { host: "a".to_owned(), retries: 1, state: false };
+    assert!(connection.replace_host("b".to_owned()) == "a");
+    assert!(connection.host() == "b");
+    assert!(connection.replace_attempts(2) == 1);
+    assert!(*connection.attempts() == 2);
+    assert!(!connection.state);
+}
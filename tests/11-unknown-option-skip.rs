@@ -0,0 +1,9 @@
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Typo {
+    #[getter(skp)]
+    field: u64,
+}
+
+fn main() {}
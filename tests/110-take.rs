@@ -0,0 +1,27 @@
+//! Taking Option and Default fields.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Upload {
+    #[getter(take)]
+    name: Option<String>,
+    #[getter(take)]
+    chunks: Vec<u8>,
+    #[getter(take)]
+    #[getter(rename = "attempts")]
+    retries: std::option::Option<u8>,
+}
+
+fn main() {
+    let mut upload = Upload { name: Some("photo".to_owned()), chunks: vec![1, 2], retries: Some(3) };
+    assert!(upload.take_name() == Some("photo".to_owned()));
+    assert!(upload.name().is_none());
+    assert!(upload.take_name().is_none());
+
+    assert!(upload.take_chunks() == vec![1, 2]);
+    assert!(upload.chunks().is_empty());
+
+    assert!(upload.take_attempts() == Some(3));
+    assert!(upload.attempts().is_none());
+}
@@ -0,0 +1,37 @@
+//! AsMut on named and tuple single field structs.
+
+use derive_getters::AsMut;
+
+#[derive(AsMut)]
+struct Meters(f64);
+
+#[derive(AsMut)]
+struct Buffer<T>
+where
+    T: Clone,
+{
+    items: Vec<T>,
+}
+
+#[derive(AsMut)]
+struct Borrowed<'a, T: ?Sized>(&'a mut T);
+
+fn double<T: AsMut<f64>>(mut value: T) -> T {
+    *value.as_mut() *= 2.0;
+    value
+}
+
+fn main() {
+    let distance = double(Meters(1.5));
+    assert!(distance.0 == 3.0);
+
+    let mut buffer = Buffer { items: vec![1u8] };
+    AsMut::<Vec<u8>>::as_mut(&mut buffer).push(2);
+    assert!(buffer.items == vec![1, 2]);
+
+    let mut text = String::from("a");
+    let mut borrowed = Borrowed(&mut text);
+    let inner: &mut &mut String = borrowed.as_mut();
+    inner.push('b');
+    assert!(text == "ab");
+}
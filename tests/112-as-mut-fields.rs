@@ -0,0 +1,11 @@
+//! AsMut needs a struct of exactly one field.
+
+use derive_getters::AsMut;
+
+#[derive(AsMut)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+//! Hashing a field is stable within a run.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Request {
+    #[getter(hash)]
+    path: String,
+    #[getter(hash)]
+    #[getter(rename = "key")]
+    query: (u8, &'static str),
+}
+
+fn main() {
+    let first = Request { path: "/index".to_owned(), query: (1, "a") };
+    let second = Request { path: "/index".to_owned(), query: (2, "b") };
+    assert!(first.path_hash() == first.path_hash());
+    assert!(first.path_hash() == second.path_hash());
+    assert!(first.key_hash() != second.key_hash());
+
+    let other = Request { path: "/about".to_owned(), query: (1, "a") };
+    assert!(first.path_hash() != other.path_hash());
+}
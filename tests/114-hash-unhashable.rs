@@ -0,0 +1,11 @@
+//! hash needs the field to be Hash.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Reading {
+    #[getter(hash)]
+    value: f64,
+}
+
+fn main() {}
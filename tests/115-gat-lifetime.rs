@@ -0,0 +1,68 @@
+//! Getters with a named borrow fit traits with generic associated types.
+
+use derive_getters::Getters;
+
+trait Lend {
+    type Loan<'a>
+    where
+        Self: 'a;
+
+    fn lend<'a>(&'a self) -> Self::Loan<'a>
+    where
+        Self: 'a;
+}
+
+#[derive(Getters)]
+struct Shelf<'b, T> {
+    #[getter(gat_lifetime)]
+    books: Vec<T>,
+    #[getter(gat_lifetime)]
+    label: &'b str,
+    #[getter(gat_lifetime)]
+    #[getter(copy)]
+    count: usize,
+}
+
+// The struct's own `'getter` isn't shadowed by the one the derive names.
+#[derive(Getters)]
+#[getter(free_fn)]
+struct Named<'getter> {
+    #[getter(gat_lifetime)]
+    name: &'getter str,
+    #[getter(gat_lifetime)]
+    tags: Vec<&'getter str>,
+}
+
+impl<'b, T> Lend for Shelf<'b, T> {
+    type Loan<'a> = &'a [T] where Self: 'a;
+
+    fn lend<'a>(&'a self) -> Self::Loan<'a>
+    where
+        Self: 'a,
+    {
+        self.books().as_slice()
+    }
+}
+
+fn first<L>(lender: &L) -> usize
+where
+    for<'a> L: Lend<Loan<'a> = &'a [u8]>,
+{
+    lender.lend().len()
+}
+
+fn main() {
+    let shelf = Shelf { books: vec![1u8, 2], label: "fiction", count: 2 };
+    assert!(first(&shelf) == 2);
+    assert!(shelf.books().len() == 2);
+    assert!(shelf.label() == "fiction");
+    assert!(shelf.count() == 2);
+
+    // A reference field is returned with its own lifetime, outliving the borrow.
+    let name = {
+        let named = Named { name: "ferris", tags: vec!["crab"] };
+        assert!(named.tags().len() == 1 && named_tags(&named)[0] == "crab");
+        named.name()
+    };
+    assert!(name == "ferris");
+}
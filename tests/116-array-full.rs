@@ -0,0 +1,22 @@
+//! A whole array as a slice and each of its elements.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Pixel {
+    #[getter(array_full)]
+    rgb: [u8; 3],
+    #[getter(array_full)]
+    #[getter(rename = "corners")]
+    points: [(i32, i32); 2],
+}
+
+fn main() {
+    let pixel = Pixel { rgb: [1, 2, 3], points: [(0, 0), (4, 4)] };
+    assert!(pixel.rgb() == &[1, 2, 3][..]);
+    assert!(*pixel.rgb_0() == 1);
+    assert!(*pixel.rgb_1() == 2);
+    assert!(*pixel.rgb_2() == 3);
+    assert!(pixel.corners().len() == 2);
+    assert!(*pixel.corners_1() == (4, 4));
+}
@@ -0,0 +1,34 @@
+//! Grouping the last fields of a dissolve into a nested tuple.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(group_tail(2), from_impl, stable_arity = 3)]
+struct Packet {
+    id: u32,
+    kind: u8,
+    header: Vec<u8>,
+    payload: String,
+}
+
+#[derive(Dissolve)]
+#[dissolve(group_tail(2))]
+struct Pair<T>(T, T);
+
+fn consume(tail: (Vec<u8>, String)) -> usize {
+    tail.0.len() + tail.1.len()
+}
+
+fn main() {
+    let packet = Packet { id: 7, kind: 1, header: vec![0, 1], payload: "abc".to_owned() };
+    let (id, kind, tail) = packet.dissolve();
+    assert!(id == 7 && kind == 1);
+    assert!(consume(tail) == 5);
+
+    let packet = Packet { id: 8, kind: 2, header: Vec::new(), payload: String::new() };
+    let (_, _, (header, payload)): (u32, u8, (Vec<u8>, String)) = packet.into();
+    assert!(header.is_empty() && payload.is_empty());
+
+    let ((a, b),) = Pair(1, 2).dissolve();
+    assert!(a + b == 3);
+}
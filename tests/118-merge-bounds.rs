@@ -0,0 +1,30 @@
+//! Field bounds merged into one where clause.
+
+use std::{fmt::Display, hash::Hash};
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(merge_bounds, free_fn)]
+struct Labelled<K, V>
+where
+    K: Clone,
+{
+    #[getter(bound = "K: Hash")]
+    key: K,
+    #[getter(bound = "V: Display")]
+    #[getter(bound = "K: Hash")]
+    value: V,
+    count: usize,
+}
+
+fn describe<K: Clone + Hash, V: Display>(labelled: &Labelled<K, V>) -> String {
+    format!("{}:{}", labelled.value(), labelled.count())
+}
+
+fn main() {
+    let labelled = Labelled { key: "a", value: 1.5, count: 2 };
+    assert!(*labelled.key() == "a");
+    assert!(*labelled_value(&labelled) == 1.5);
+    assert!(describe(&labelled) == "1.5:2");
+}
@@ -0,0 +1,25 @@
+//! Separate getters for each side of a Result field.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Lookup {
+    #[getter(result_split)]
+    port: Result<u32, String>,
+    #[getter(result_split)]
+    #[getter(rename = "addr")]
+    address: std::result::Result<&'static str, std::io::ErrorKind>,
+}
+
+fn main() {
+    let found = Lookup { port: Ok(80), address: Ok("localhost") };
+    assert!(found.port_ok() == Some(&80));
+    assert!(found.port_err().is_none());
+    assert!(found.port().is_ok());
+    assert!(found.addr_ok() == Some(&"localhost"));
+
+    let missing = Lookup { port: Err("unset".to_owned()), address: Err(std::io::ErrorKind::NotFound) };
+    assert!(missing.port_ok().is_none());
+    assert!(missing.port_err().map(String::as_str) == Some("unset"));
+    assert!(missing.addr_err() == Some(&std::io::ErrorKind::NotFound));
+}
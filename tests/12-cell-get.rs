@@ -0,0 +1,33 @@
+//! Value getters through `Cell`.
+
+use std::cell::Cell;
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Counter {
+    #[getter(cell_get)]
+    hits: Cell<u32>,
+
+    #[getter(cell_get)]
+    #[getter(rename = "misses")]
+    missed: std::cell::Cell<u32>,
+
+    cell: Cell<u32>,
+}
+
+fn main() {
+    let counter = Counter {
+        hits: Cell::new(1),
+        missed: Cell::new(10),
+        cell: Cell::new(100),
+    };
+
+    let hits: u32 = counter.hits();
+    assert!(hits == 1);
+
+    counter.cell().set(counter.hits() + counter.misses());
+    counter.hits.set(2);
+    assert!(counter.hits() == 2);
+    assert!(counter.misses() == 10);
+    assert!(counter.cell().get() == 11);
+}
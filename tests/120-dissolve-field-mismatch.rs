@@ -0,0 +1,15 @@
+//! A field of the wrong type for the variant is reported at the field.
+
+use derive_getters::Dissolve;
+
+pub enum State {
+    Done { code: i32 },
+}
+
+#[derive(Dissolve)]
+#[dissolve(into_variant = "State::Done")]
+struct Finished {
+    code: u8,
+}
+
+fn main() {}
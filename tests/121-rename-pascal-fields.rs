@@ -0,0 +1,24 @@
+//! PascalCase fields renamed to snake case getters.
+
+use derive_getters::Getters;
+
+#[allow(non_snake_case)]
+#[derive(Getters)]
+#[getter(rename_all = "snake_case")]
+struct Record {
+    FirstName: String,
+    HTTPServer: String,
+    Utf8Text: String,
+}
+
+fn main() {
+    let record = Record {
+        FirstName: "Ada".to_string(),
+        HTTPServer: "example.org".to_string(),
+        Utf8Text: "text".to_string(),
+    };
+
+    assert!(record.first_name() == "Ada");
+    assert!(record.http_server() == "example.org");
+    assert!(record.utf8_text() == "text");
+}
@@ -0,0 +1,49 @@
+//! Mutably borrowing dissolve, editing several fields at once.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(mut)]
+struct Counter {
+    count: u32,
+    label: String,
+}
+
+#[derive(Dissolve)]
+#[dissolve(by_ref, mut, explicit_lifetime)]
+struct Annotated<'a, T> {
+    name: &'a str,
+    value: T,
+}
+
+#[derive(Dissolve)]
+#[dissolve(rename = "split", mut)]
+struct Tuple(u8, #[dissolve(skip)] char, String);
+
+// The named lifetime lets the borrow outlive the call in generic code.
+fn value<'s, T>(annotated: &'s mut Annotated<'_, T>) -> &'s mut T {
+    let (_, value) = annotated.dissolve_mut();
+    value
+}
+
+fn main() {
+    let mut counter = Counter { count: 1, label: "one".to_owned() };
+    let (count, label) = counter.dissolve_mut();
+    *count += 1;
+    label.push_str(" more");
+    assert!(counter.count == 2 && counter.label == "one more");
+
+    let mut annotated = Annotated { name: "ferris", value: 2.5 };
+    let (name, value) = annotated.dissolve_mut();
+    *name = "corro";
+    *value *= 2.0;
+    *self::value(&mut annotated) += 1.0;
+    let (name, value) = annotated.dissolve_ref();
+    assert!(*name == "corro" && *value == 6.0);
+
+    let mut tuple = Tuple(1, 'x', "three".to_owned());
+    let (one, three) = tuple.split_mut();
+    *one = 3;
+    *three = "one".to_owned();
+    assert!(tuple.0 == 3 && tuple.1 == 'x' && tuple.2 == "one");
+}
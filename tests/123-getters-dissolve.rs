@@ -0,0 +1,33 @@
+//! Getters and a dissolve method from the one derive.
+
+use derive_getters::GettersDissolve;
+
+#[derive(GettersDissolve)]
+#[getter(prefix = "get_")]
+#[dissolve(by_ref)]
+struct Number {
+    #[getter(copy)]
+    num: u64,
+
+    #[getter(rename = "label")]
+    #[dissolve(skip)]
+    name: String,
+}
+
+#[derive(GettersDissolve)]
+#[dissolve(rename = "split")]
+struct Generic<T> {
+    value: T,
+}
+
+fn main() {
+    let number = Number { num: 655, name: "six".to_owned() };
+    assert!(number.get_num() == 655);
+    assert!(number.label() == "six");
+    assert!(number.dissolve_ref() == (&655,));
+    assert!(number.dissolve() == (655,));
+
+    let generic = Generic { value: 'v' };
+    assert!(*generic.value() == 'v');
+    assert!(generic.split() == ('v',));
+}
@@ -0,0 +1,12 @@
+//! Errors from both halves of the combined derive are reported.
+
+use derive_getters::GettersDissolve;
+
+#[derive(GettersDissolve)]
+#[dissolve(shatter)]
+struct Number {
+    #[getter(skipp)]
+    num: u64,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+//! A deprecated field's getter is deprecated too, warning where it's called.
+#![deny(deprecated)]
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Config {
+    #[deprecated(since = "0.2.0", note = "use `timeout` instead")]
+    #[getter(alias = "wait")]
+    delay: u64,
+
+    timeout: u64,
+}
+
+fn main() {
+    #[allow(deprecated)]
+    let config = Config { delay: 1, timeout: 2 };
+    assert!(*config.timeout() == 2);
+    assert!(*config.delay() == 1);
+    assert!(*config.wait() == 1);
+}
@@ -0,0 +1,44 @@
+//! Getters on an enum of one struct-like variant, as if it were a struct.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+enum Message<T> {
+    Text {
+        body: String,
+        #[getter(copy)]
+        id: u32,
+        #[getter(as_ref)]
+        reply_to: Option<T>,
+        #[getter(skip)]
+        #[allow(dead_code)]
+        cache: Vec<u8>,
+        #[getter(replace)]
+        #[getter(eq)]
+        priority: u8,
+    },
+}
+
+#[derive(Getters)]
+#[getter(positional_names = ["x", "y"])]
+enum Point {
+    At(i32, i32),
+}
+
+fn main() {
+    let mut message = Message::Text {
+        body: "hello".to_owned(),
+        id: 3,
+        reply_to: Some(1u64),
+        cache: Vec::new(),
+        priority: 1,
+    };
+    assert!(message.body() == "hello");
+    assert!(message.id() == 3);
+    assert!(message.reply_to() == Some(&1));
+    assert!(message.replace_priority(2) == 1);
+    assert!(message.priority_eq(&2));
+
+    let point = Point::At(1, 2);
+    assert!(*point.x() == 1 && *point.y() == 2);
+}
@@ -0,0 +1,22 @@
+//! Only an enum of a single variant gets getters, and not options building the struct.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+enum Many {
+    First { a: u8 },
+    Second { a: u8 },
+}
+
+#[derive(Getters)]
+#[getter(new)]
+enum Constructed {
+    Only { a: u8 },
+}
+
+#[derive(Getters)]
+enum Unit {
+    Only,
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+//! Consuming the struct to extract a single field.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Triple<T> {
+    #[getter(consume)]
+    first: String,
+
+    #[getter(consume)]
+    #[getter(rename = "middle")]
+    second: Vec<u8>,
+
+    #[getter(consume)]
+    third: T,
+}
+
+#[derive(Getters)]
+enum Wrapped {
+    Only {
+        #[getter(consume)]
+        value: String,
+    },
+}
+
+fn triple() -> Triple<Box<u32>> {
+    Triple {
+        first: "one".to_owned(),
+        second: vec![2],
+        third: Box::new(3),
+    }
+}
+
+fn main() {
+    assert!(triple().into_first() == "one");
+    assert!(triple().into_middle() == vec![2]);
+    assert!(*triple().into_third() == 3);
+
+    let triple = triple();
+    assert!(triple.first() == "one");
+    assert!(triple.into_third() == Box::new(3));
+
+    let wrapped = Wrapped::Only { value: "inner".to_owned() };
+    assert!(wrapped.value() == "inner");
+    assert!(wrapped.into_value() == "inner");
+}
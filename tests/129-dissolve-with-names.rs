@@ -0,0 +1,25 @@
+//! Dissolving with a closure, where the struct's own names match the generated ones.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(with)]
+struct Callback {
+    f: u8,
+    g: u8,
+}
+
+#[derive(Dissolve)]
+#[dissolve(with)]
+struct Generic<R> {
+    value: R,
+    count: usize,
+}
+
+fn main() {
+    let callback = Callback { f: 1, g: 2 };
+    assert!(callback.dissolve_with(|f, g| f + g) == 3);
+
+    let generic = Generic { value: "r", count: 2 };
+    assert!(generic.dissolve_with(|value, count| value.repeat(count)) == "rr");
+}
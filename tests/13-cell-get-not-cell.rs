@@ -0,0 +1,9 @@
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct NotCell {
+    #[getter(cell_get)]
+    hits: u32,
+}
+
+fn main() {}
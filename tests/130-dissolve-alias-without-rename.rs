@@ -0,0 +1,19 @@
+//! An alias needs a renamed method to forward to.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(with)]
+#[dissolve(alias)]
+struct Plain {
+    a: u8,
+    b: u8,
+}
+
+#[derive(Dissolve)]
+#[dissolve(rename = "dissolve", alias)]
+struct Same {
+    a: u8,
+}
+
+fn main() {}
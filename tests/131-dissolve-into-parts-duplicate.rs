@@ -0,0 +1,14 @@
+//! A field can only be taken into parts once.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(into_parts(id, name))]
+#[dissolve(into_parts(name))]
+struct User {
+    id: u64,
+    name: String,
+    admin: bool,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+//! Strict mode leaves other crates' attributes named like `getter` alone.
+
+use derive_getters::Getters;
+use setters::Setters;
+
+#[derive(Getters, Setters)]
+#[getter(strict)]
+#[setters(prefix = "set_")]
+struct Config {
+    #[setter(skip)]
+    name: String,
+
+    #[getter(skip)]
+    #[setter(into)]
+    #[allow(dead_code)]
+    port: u16,
+}
+
+fn main() {
+    let config = Config { name: "strict".to_owned(), port: 80 };
+    assert!(config.name() == "strict");
+}
@@ -0,0 +1,23 @@
+//! Options whose getters need `std` fail at their field in a `no_std` crate.
+#![no_std]
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct NeedsStd<'a> {
+    #[getter(hash)]
+    id: u64,
+
+    #[getter(to_owned)]
+    name: &'a str,
+
+    #[getter(copy)]
+    count: u32,
+}
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
+
+fn main() {}
@@ -0,0 +1,57 @@
+//! Generated items touching a deprecated field don't warn, only callers of its getter.
+#![deny(deprecated)]
+
+use derive_getters::{Dissolve, Getters, GettersOwned};
+
+pub struct ConfigSnapshot {
+    pub delay: u64,
+    pub timeout: u64,
+}
+
+#[derive(Getters)]
+#[getter(new, snapshot = "ConfigSnapshot", free_fn)]
+struct Config {
+    #[deprecated(since = "0.2.0", note = "use `timeout` instead")]
+    #[getter(alias = "wait")]
+    delay: u64,
+
+    timeout: u64,
+}
+
+#[derive(Dissolve)]
+#[dissolve(from_impl, try_from, with, by_ref)]
+struct Request {
+    #[deprecated(note = "use `body` instead")]
+    payload: String,
+
+    body: String,
+}
+
+#[derive(GettersOwned)]
+struct Limits {
+    #[deprecated(note = "use `max` instead")]
+    limit: u32,
+
+    max: u32,
+}
+
+fn main() {
+    let config = Config::new(1, 2);
+    assert!(*config.timeout() == 2 && *config_timeout(&config) == 2);
+    assert!(config.snapshot().timeout == 2);
+
+    let request = Request::from(("a".to_owned(), "b".to_owned()));
+    assert!(request.dissolve_ref().1 == "b");
+    let (_, body) = <(String, String)>::from(request);
+    assert!(body == "b");
+
+    let limits = Limits::from_max(3);
+    assert!(limits.max() == 3);
+}
+
+impl Limits {
+    fn from_max(max: u32) -> Self {
+        #[allow(deprecated)]
+        Limits { limit: 0, max }
+    }
+}
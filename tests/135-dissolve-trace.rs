@@ -0,0 +1,20 @@
+//! A traced dissolve compiles with the `tracing` feature, calling into the `tracing` crate.
+
+use derive_getters::Dissolve;
+
+// Not the crate, so a relative `tracing::trace!` would fail to resolve.
+#[allow(dead_code)]
+mod tracing {}
+
+#[derive(Dissolve)]
+#[dissolve(trace, rename = "split")]
+struct Traced {
+    a: u64,
+    b: String,
+}
+
+fn main() {
+    let traced = Traced { a: 1, b: "two".to_owned() };
+    let (a, b) = traced.split();
+    assert!(a == 1 && b == "two");
+}
@@ -0,0 +1,12 @@
+//! explicit_lifetime names the borrow of by_ref or mut, so it needs one of them.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(explicit_lifetime)]
+struct Owned {
+    a: u8,
+    b: String,
+}
+
+fn main() {}
@@ -0,0 +1,48 @@
+//! Dissolving through `From`/`Into`.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(from_impl)]
+struct Stuff {
+    name: String,
+    price: f64,
+    count: usize,
+}
+
+#[derive(Dissolve)]
+#[dissolve(from_impl)]
+#[dissolve(rename = "unmake")]
+struct Generic<'a, T: Clone> {
+    label: &'a str,
+    value: T,
+}
+
+fn total<S: Into<(String, f64, usize)>>(stuff: S) -> f64 {
+    let (_, price, count) = stuff.into();
+    price * count as f64
+}
+
+fn main() {
+    let stuff = Stuff { name: "Hogie".to_owned(), price: 2.5, count: 4 };
+    let (n, p, c): (String, f64, usize) = stuff.into();
+    assert!(n == "Hogie");
+    assert!(p == 2.5);
+    assert!(c == 4);
+
+    let stuff = Stuff { name: "Hogie".to_owned(), price: 2.5, count: 4 };
+    assert!(total(stuff) == 10.0);
+
+    let stuff = Stuff { name: "Hogie".to_owned(), price: 2.5, count: 4 };
+    let (n, _, _) = stuff.dissolve();
+    assert!(n == "Hogie");
+
+    let generic = Generic { label: "answer", value: 42u64 };
+    let (label, value): (&str, u64) = generic.into();
+    assert!(label == "answer");
+    assert!(value == 42);
+
+    let generic = Generic { label: "answer", value: 42u64 };
+    let (label, _) = generic.unmake();
+    assert!(label == "answer");
+}
@@ -0,0 +1,33 @@
+//! Renames given as string literals or bare identifiers.
+
+use derive_getters::{Getters, Dissolve};
+
+#[derive(Getters, Dissolve)]
+#[dissolve(rename = "shatter")]
+struct Literal {
+    #[getter(rename = "number")]
+    field: u64,
+    other: u64,
+}
+
+#[derive(Getters, Dissolve)]
+#[dissolve(rename = crumble)]
+struct Bare {
+    #[getter(rename = number)]
+    field: u64,
+    other: u64,
+}
+
+fn main() {
+    let literal = Literal { field: 1, other: 2 };
+    assert!(*literal.number() == 1);
+    assert!(*literal.other() == 2);
+    let (field, other) = literal.shatter();
+    assert!(field == 1 && other == 2);
+
+    let bare = Bare { field: 3, other: 4 };
+    assert!(*bare.number() == 3);
+    assert!(*bare.other() == 4);
+    let (field, other) = bare.crumble();
+    assert!(field == 3 && other == 4);
+}
@@ -0,0 +1,34 @@
+//! Getters returning several related fields at once.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(group(point, x, y))]
+struct Location {
+    x: i64,
+    y: i64,
+    label: String,
+}
+
+#[derive(Getters)]
+#[getter(group(pair, first, second))]
+#[getter(group(reversed, second, first))]
+struct Pair<'a, T> {
+    first: T,
+
+    #[getter(skip)]
+    second: &'a str,
+}
+
+fn main() {
+    let location = Location { x: 3, y: -4, label: "here".to_owned() };
+    let (x, y) = location.point();
+    assert!(*x == 3);
+    assert!(*y == -4);
+    assert!(location.label() == "here");
+
+    let pair = Pair { first: 1u8, second: "two" };
+    assert!(pair.pair() == (&1, &"two"));
+    assert!(pair.reversed() == (&"two", &1));
+    assert!(*pair.first() == 1);
+}
@@ -0,0 +1,10 @@
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(group(point, x, z))]
+struct Location {
+    x: i64,
+    y: i64,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+//! Fallible conversion getters.
+
+use std::num::NonZeroU64;
+use std::convert::TryFrom;
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Validated {
+    #[getter(try_into = "NonZeroU64")]
+    count: u64,
+
+    #[getter(try_into = "u8")]
+    #[getter(rename = "small")]
+    wide: i32,
+}
+
+fn main() {
+    let valid = Validated { count: 12, wide: 200 };
+    let count: NonZeroU64 = valid.count().unwrap();
+    assert!(count.get() == 12);
+    assert!(valid.small() == Ok(200));
+
+    let invalid = Validated { count: 0, wide: -1 };
+    assert!(invalid.count().is_err());
+    assert!(invalid.count() == NonZeroU64::try_from(0u64));
+    assert!(invalid.small().is_err());
+}
@@ -0,0 +1,10 @@
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Conflict {
+    #[getter(as_slice)]
+    #[getter(try_into = "Vec<u8>")]
+    bytes: [u8; 4],
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+//! Getters computed from expressions over several fields.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(expr(area = "self.width * self.height", ty = "u64"))]
+#[getter(expr(perimeter = "2 * (self.width + self.height)", ty = "u64"))]
+#[getter(expr(is_square = "self.width == self.height", ty = "bool"))]
+struct Rectangle {
+    width: u64,
+    height: u64,
+}
+
+#[derive(Getters)]
+#[getter(expr(full_name = "format!(\"{} {}\", self.first, self.last)", ty = "String",))]
+struct Person {
+    first: String,
+    last: String,
+}
+
+fn main() {
+    let rectangle = Rectangle { width: 3, height: 4 };
+    assert!(rectangle.area() == 12);
+    assert!(rectangle.perimeter() == 14);
+    assert!(!rectangle.is_square());
+    assert!(*rectangle.width() == 3);
+
+    let person = Person { first: "Ada".to_owned(), last: "Lovelace".to_owned() };
+    assert!(person.full_name() == "Ada Lovelace");
+}
@@ -0,0 +1,31 @@
+//! Both derives on a `#[non_exhaustive]` struct.
+
+use derive_getters::{Getters, Dissolve};
+
+#[derive(Getters, Dissolve)]
+#[non_exhaustive]
+struct Config {
+    name: String,
+    retries: u32,
+}
+
+#[derive(Getters, Dissolve)]
+#[non_exhaustive]
+#[dissolve(rename = "into_parts")]
+struct Generic<T> {
+    value: T,
+    count: usize,
+}
+
+fn main() {
+    let config = Config { name: "service".to_owned(), retries: 3 };
+    assert!(config.name() == "service");
+    assert!(*config.retries() == 3);
+    let (name, retries) = config.dissolve();
+    assert!(name == "service" && retries == 3);
+
+    let generic = Generic { value: 'x', count: 1 };
+    assert!(*generic.value() == 'x');
+    let (value, count) = generic.into_parts();
+    assert!(value == 'x' && count == 1);
+}
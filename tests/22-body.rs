@@ -0,0 +1,37 @@
+//! Getters with their body overridden.
+
+use derive_getters::Getters;
+
+struct Inner {
+    text: String,
+}
+
+impl Inner {
+    fn as_str(&self) -> &str {
+        self.text.as_str()
+    }
+}
+
+#[derive(Getters)]
+struct Custom {
+    #[getter(body = "self.inner.as_str()", ty = "&str")]
+    inner: Inner,
+
+    #[getter(body = "self.count * 2", ty = "u64")]
+    #[getter(rename = "doubled")]
+    count: u64,
+
+    plain: u64,
+}
+
+fn main() {
+    let custom = Custom {
+        inner: Inner { text: "inside".to_owned() },
+        count: 21,
+        plain: 7,
+    };
+
+    assert!(custom.inner() == "inside");
+    assert!(custom.doubled() == 42);
+    assert!(*custom.plain() == 7);
+}
@@ -0,0 +1,33 @@
+//! Structs where every getter is skipped.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Nothing {
+    #[getter(skip)]
+    hidden: u64,
+}
+
+#[derive(Getters)]
+#[getter(keep_empty_impl)]
+struct Empty<T> {
+    #[getter(skip)]
+    hidden: T,
+}
+
+#[derive(Getters)]
+#[getter(keep_empty_impl)]
+struct NotEmpty {
+    shown: u64,
+}
+
+fn main() {
+    let nothing = Nothing { hidden: 1 };
+    assert!(nothing.hidden == 1);
+
+    let empty = Empty { hidden: 2 };
+    assert!(empty.hidden == 2);
+
+    let not_empty = NotEmpty { shown: 3 };
+    assert!(*not_empty.shown() == 3);
+}
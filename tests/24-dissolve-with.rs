@@ -0,0 +1,38 @@
+//! Dissolving straight into a closure.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(with)]
+struct Stuff {
+    name: String,
+    price: f64,
+    count: usize,
+}
+
+#[derive(Dissolve)]
+#[dissolve(with)]
+#[dissolve(rename = "shatter")]
+struct Generic<'a, T: Clone> where T: Default {
+    label: &'a str,
+    values: Vec<T>,
+}
+
+fn main() {
+    let stuff = Stuff { name: "Hogie".to_owned(), price: 2.5, count: 4 };
+    let summary = stuff.dissolve_with(|name, price, count| {
+        format!("{}: {}", name, price * count as f64)
+    });
+    assert!(summary == "Hogie: 10");
+
+    let stuff = Stuff { name: "Hogie".to_owned(), price: 2.5, count: 4 };
+    let (name, _, _) = stuff.dissolve();
+    assert!(name == "Hogie");
+
+    let generic = Generic { label: "sum", values: vec![1u32, 2, 3] };
+    let (label, sum) = generic.shatter_with(|label, values| {
+        (label, values.iter().sum::<u32>())
+    });
+    assert!(label == "sum");
+    assert!(sum == 6);
+}
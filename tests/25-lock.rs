@@ -0,0 +1,28 @@
+//! Getters locking `Mutex` fields.
+
+use std::sync::Mutex;
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Shared<T> {
+    #[getter(lock)]
+    items: Mutex<Vec<T>>,
+
+    #[getter(lock = "try")]
+    #[getter(rename = "try_count")]
+    count: std::sync::Mutex<u64>,
+}
+
+fn main() {
+    let shared = Shared { items: Mutex::new(vec![1, 2]), count: Mutex::new(0) };
+
+    shared.items().push(3);
+    assert!(*shared.items() == vec![1, 2, 3]);
+
+    {
+        let mut count = shared.try_count().unwrap();
+        *count += 1;
+        assert!(shared.try_count().is_err());
+    }
+    assert!(*shared.try_count().unwrap() == 1);
+}
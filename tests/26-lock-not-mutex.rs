@@ -0,0 +1,10 @@
+use std::sync::RwLock;
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct NotMutex {
+    #[getter(lock)]
+    items: RwLock<Vec<u64>>,
+}
+
+fn main() {}
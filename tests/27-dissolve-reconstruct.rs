@@ -0,0 +1,35 @@
+//! Round trips through dissolve and reconstruct.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve, Clone, PartialEq, Debug)]
+#[dissolve(reconstruct)]
+struct Stuff {
+    name: String,
+    price: f64,
+    count: usize,
+}
+
+#[derive(Dissolve, Clone, PartialEq, Debug)]
+#[dissolve(reconstruct)]
+#[dissolve(rename = "shatter")]
+struct Generic<'a, T> {
+    label: &'a str,
+    value: T,
+}
+
+fn main() {
+    let stuff = Stuff { name: "Hogie".to_owned(), price: 2.5, count: 4 };
+    let rebuilt = Stuff::reconstruct(stuff.clone().dissolve());
+    assert!(rebuilt == stuff);
+
+    let (name, price, count) = stuff.dissolve();
+    let rebuilt = Stuff::reconstruct((name.to_uppercase(), price * 2.0, count + 1));
+    assert!(rebuilt.name == "HOGIE");
+    assert!(rebuilt.price == 5.0);
+    assert!(rebuilt.count == 5);
+
+    let generic = Generic { label: "answer", value: 42u64 };
+    let rebuilt = Generic::reconstruct(generic.clone().shatter());
+    assert!(rebuilt == generic);
+}
@@ -0,0 +1,44 @@
+//! Iterator accessors for collection fields.
+
+use std::collections::{HashMap, BTreeMap};
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Collections<T> {
+    #[getter(iter)]
+    names: Vec<String>,
+
+    #[getter(iter)]
+    scores: HashMap<String, u32>,
+
+    #[getter(iter)]
+    #[getter(rename = "ordered")]
+    sorted: BTreeMap<u8, T>,
+}
+
+fn main() {
+    let mut scores = HashMap::new();
+    scores.insert("a".to_owned(), 1);
+    scores.insert("b".to_owned(), 2);
+
+    let mut sorted = BTreeMap::new();
+    sorted.insert(2, 'b');
+    sorted.insert(1, 'a');
+
+    let collections = Collections {
+        names: vec!["x".to_owned(), "y".to_owned()],
+        scores,
+        sorted,
+    };
+
+    let names: Vec<&String> = collections.names_iter().collect();
+    assert!(names == vec!["x", "y"]);
+    assert!(collections.names().len() == 2);
+
+    let total: u32 = collections.scores_iter().map(|(_, score)| *score).sum();
+    assert!(total == 3);
+
+    let ordered: Vec<(&u8, &char)> = collections.ordered_iter().collect();
+    assert!(ordered == vec![(&1, &'a'), (&2, &'b')]);
+    assert!(collections.ordered().len() == 2);
+}
@@ -0,0 +1,34 @@
+//! A renamed dissolve keeping `dissolve` as an alias.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(rename = "shatter")]
+#[dissolve(alias)]
+#[dissolve(with)]
+struct Glass<T> {
+    shards: u64,
+    colour: String,
+    extra: T,
+}
+
+impl<T> Glass<T> {
+    fn new(extra: T) -> Self {
+        Glass { shards: 12, colour: "green".to_owned(), extra }
+    }
+}
+
+fn main() {
+    let (shards, colour, extra) = Glass::new('x').shatter();
+    assert!(shards == 12);
+    assert!(colour == "green");
+    assert!(extra == 'x');
+
+    let (shards, colour, extra) = Glass::new(5u8).dissolve();
+    assert!(shards == 12);
+    assert!(colour == "green");
+    assert!(extra == 5);
+
+    let total = Glass::new(1u64).shatter_with(|shards, _, extra| shards + extra);
+    assert!(total == 13);
+}
@@ -0,0 +1,43 @@
+//! Const generic where-clauses survive into the generated impls.
+
+use derive_getters::{Getters, Dissolve};
+
+struct Check<const N: usize>;
+
+trait Valid {}
+
+impl Valid for Check<4> {}
+impl Valid for Check<8> {}
+
+#[derive(Getters, Dissolve)]
+struct Buffer<T, const N: usize>
+where
+    [(); N]: Sized,
+    Check<N>: Valid,
+    T: Copy,
+{
+    #[getter(as_slice)]
+    data: [T; N],
+    len: usize,
+}
+
+impl<T: Copy, const N: usize> Buffer<T, N>
+where
+    [(); N]: Sized,
+    Check<N>: Valid,
+{
+    fn new(fill: T) -> Self {
+        Buffer { data: [fill; N], len: N }
+    }
+}
+
+fn main() {
+    let small: Buffer<u8, 4> = Buffer::new(1);
+    assert!(small.data() == &[1, 1, 1, 1]);
+    assert!(small.len() == &4);
+
+    let large: Buffer<char, 8> = Buffer::new('z');
+    let (data, len) = large.dissolve();
+    assert!(data == ['z'; 8]);
+    assert!(len == 8);
+}
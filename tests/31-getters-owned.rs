@@ -0,0 +1,47 @@
+//! By-value getters on an all `Copy` struct.
+
+use derive_getters::{Getters, GettersOwned};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Colour {
+    Red,
+    Blue,
+}
+
+#[derive(GettersOwned)]
+struct Pixel<'a, T: Copy> {
+    x: u32,
+    y: u32,
+    colour: Colour,
+    alpha: T,
+    tag: &'a str,
+}
+
+mod plain {
+    use super::*;
+
+    #[derive(Getters)]
+    pub struct Plain {
+        count: u64,
+    }
+
+    impl Plain {
+        pub fn new(count: u64) -> Self {
+            Plain { count }
+        }
+    }
+}
+
+fn main() {
+    let pixel = Pixel { x: 1, y: 2, colour: Colour::Blue, alpha: 0.5f32, tag: "corner" };
+    let x: u32 = pixel.x();
+    assert!(x == 1);
+    assert!(pixel.y() == 2);
+    assert!(pixel.colour() == Colour::Blue);
+    assert!(pixel.colour() != Colour::Red);
+    assert!(pixel.alpha() == 0.5);
+    assert!(pixel.tag() == "corner");
+
+    let plain = plain::Plain::new(9);
+    assert!(plain.count() == &9);
+}
@@ -0,0 +1,11 @@
+//! GettersOwned requires every field to be `Copy`.
+
+use derive_getters::GettersOwned;
+
+#[derive(GettersOwned)]
+struct Named {
+    id: u64,
+    name: String,
+}
+
+fn main() {}
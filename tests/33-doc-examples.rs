@@ -0,0 +1,30 @@
+//! Getters documented with generated examples. Undocumented getters won't compile here.
+#![deny(missing_docs)]
+
+use derive_getters::Getters;
+
+/// A request.
+#[derive(Getters)]
+#[getter(doc_examples)]
+pub struct HttpRequest {
+    #[getter(rename = "path")]
+    uri: String,
+
+    #[getter(cell_get)]
+    retries: std::cell::Cell<u8>,
+
+    #[getter(as_slice)]
+    body: Vec<u8>,
+}
+
+fn main() {
+    let request = HttpRequest {
+        uri: "/index.html".to_owned(),
+        retries: std::cell::Cell::new(2),
+        body: Vec::new(),
+    };
+
+    assert!(request.path() == "/index.html");
+    assert!(request.retries() == 2);
+    assert!(request.body().is_empty());
+}
@@ -0,0 +1,49 @@
+//! Dissolving tuple structs while skipping positional fields.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+struct SkipFirst(#[dissolve(skip)] u8, String, f64);
+
+#[derive(Dissolve)]
+struct SkipMiddle(u8, #[dissolve(skip)] String, f64);
+
+#[derive(Dissolve)]
+#[dissolve(with)]
+#[dissolve(from_impl)]
+struct SkipLast<T>(u8, String, #[dissolve(skip)] T);
+
+#[derive(Dissolve)]
+#[dissolve(reconstruct)]
+struct Whole(u8, String, f64);
+
+#[derive(Dissolve)]
+struct Named {
+    a: u8,
+    #[dissolve(skip)]
+    b: String,
+    c: f64,
+}
+
+fn main() {
+    let (b, c) = SkipFirst(1, "two".to_owned(), 3.0).dissolve();
+    assert!(b == "two" && c == 3.0);
+
+    let (a, c) = SkipMiddle(1, "two".to_owned(), 3.0).dissolve();
+    assert!(a == 1 && c == 3.0);
+
+    let (a, b) = SkipLast(1, "two".to_owned(), 'x').dissolve();
+    assert!(a == 1 && b == "two");
+
+    let len = SkipLast(1, "two".to_owned(), ()).dissolve_with(|a, b| a as usize + b.len());
+    assert!(len == 4);
+
+    let (a, b): (u8, String) = SkipLast(1, "two".to_owned(), 3u64).into();
+    assert!(a == 1 && b == "two");
+
+    let whole = Whole::reconstruct(Whole(1, "two".to_owned(), 3.0).dissolve());
+    assert!(whole.0 == 1 && whole.1 == "two" && whole.2 == 3.0);
+
+    let (a, c) = Named { a: 1, b: "two".to_owned(), c: 3.0 }.dissolve();
+    assert!(a == 1 && c == 3.0);
+}
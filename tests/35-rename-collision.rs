@@ -0,0 +1,13 @@
+//! Colliding getters point at both fields.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Collide {
+    total: u64,
+
+    #[getter(rename = "total")]
+    sum: u64,
+}
+
+fn main() {}
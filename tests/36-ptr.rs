@@ -0,0 +1,31 @@
+//! Raw pointer getters for FFI.
+
+use derive_getters::Getters;
+
+#[repr(C)]
+#[derive(Getters)]
+struct Ffi<T> {
+    #[getter(ptr)]
+    id: u32,
+
+    #[getter(ptr_mut)]
+    #[getter(rename = "buffer_mut")]
+    buffer: [u8; 4],
+
+    #[getter(ptr)]
+    extra: T,
+}
+
+fn main() {
+    let mut ffi = Ffi { id: 7, buffer: [0; 4], extra: 'e' };
+
+    let id: *const u32 = ffi.id();
+    assert!(unsafe { *id } == 7);
+
+    let extra: *const char = ffi.extra();
+    assert!(unsafe { *extra } == 'e');
+
+    let buffer: *mut [u8; 4] = ffi.buffer_mut();
+    unsafe { (*buffer)[2] = 9; }
+    assert!(ffi.buffer == [0, 0, 9, 0]);
+}
@@ -0,0 +1,39 @@
+//! Splitting a struct into the taken fields and the rest.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(into_parts(b, d))]
+struct Four<T> {
+    a: u8,
+    b: String,
+    c: T,
+    d: f64,
+}
+
+#[derive(Dissolve)]
+#[dissolve(into_parts(c))]
+struct Skipping {
+    a: u8,
+    #[dissolve(skip)]
+    b: String,
+    c: u32,
+}
+
+fn make() -> Four<char> {
+    Four { a: 1, b: "two".to_owned(), c: '3', d: 4.0 }
+}
+
+fn main() {
+    let ((b, d), (a, c)) = make().into_parts();
+    assert!(a == 1);
+    assert!(b == "two");
+    assert!(c == '3');
+    assert!(d == 4.0);
+
+    let (a, b, c, d) = make().dissolve();
+    assert!(a == 1 && b == "two" && c == '3' && d == 4.0);
+
+    let ((c,), (a,)) = Skipping { a: 1, b: "gone".to_owned(), c: 3 }.into_parts();
+    assert!(a == 1 && c == 3);
+}
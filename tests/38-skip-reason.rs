@@ -0,0 +1,24 @@
+//! Skipping a field with a reason.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Cached {
+    value: u64,
+
+    #[getter(skip = "internal cache")]
+    cache: Option<u64>,
+}
+
+// Would collide were a getter generated for `cache`.
+impl Cached {
+    fn cache(&self) -> u64 {
+        self.cache.unwrap_or(*self.value())
+    }
+}
+
+fn main() {
+    let cached = Cached { value: 3, cache: None };
+    assert!(cached.value() == &3);
+    assert!(cached.cache() == 3);
+}
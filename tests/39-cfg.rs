@@ -0,0 +1,41 @@
+//! Getters generated only when a cfg predicate holds.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(cfg = "all()")]
+struct Enabled {
+    a: u64,
+}
+
+#[derive(Getters)]
+#[getter(cfg = "any()")]
+struct Disabled {
+    a: u64,
+}
+
+// Would collide were the getters generated.
+impl Disabled {
+    fn a(&self) -> u64 {
+        self.a + 1
+    }
+}
+
+#[derive(Getters)]
+#[getter(cfg = "all()")]
+#[getter(cfg = "not(all())")]
+struct Contradiction {
+    a: u64,
+}
+
+impl Contradiction {
+    fn a(&self) -> u64 {
+        self.a + 2
+    }
+}
+
+fn main() {
+    assert!(Enabled { a: 1 }.a() == &1);
+    assert!(Disabled { a: 1 }.a() == 2);
+    assert!(Contradiction { a: 1 }.a() == 3);
+}
@@ -0,0 +1,41 @@
+//! Representation attributes don't interfere with either derive.
+
+use derive_getters::{Getters, Dissolve};
+
+#[repr(C)]
+#[derive(Getters, Dissolve)]
+struct Ffi {
+    id: u32,
+    #[getter(rename = "flags")]
+    bits: u8,
+}
+
+#[derive(Getters)]
+#[repr(transparent)]
+struct Meters {
+    value: f64,
+}
+
+#[derive(Getters)]
+#[repr(C, align(16))]
+struct Aligned<T> {
+    #[doc = "Documented field."]
+    inner: T,
+    pad: u8,
+}
+
+fn main() {
+    let ffi = Ffi { id: 1, bits: 2 };
+    assert!(ffi.id() == &1);
+    assert!(ffi.flags() == &2);
+    let (id, bits) = ffi.dissolve();
+    assert!(id == 1 && bits == 2);
+
+    let meters = Meters { value: 2.5 };
+    assert!(meters.value() == &2.5);
+
+    let aligned = Aligned { inner: 'a', pad: 0 };
+    assert!(aligned.inner() == &'a');
+    assert!(aligned.pad() == &0);
+    assert!(std::mem::align_of::<Aligned<char>>() == 16);
+}
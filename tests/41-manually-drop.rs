@@ -0,0 +1,34 @@
+//! Getters reaching through `ManuallyDrop`.
+
+use std::mem::ManuallyDrop;
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Handle {
+    #[getter(manually_drop)]
+    name: ManuallyDrop<String>,
+
+    #[getter(manually_drop)]
+    #[getter(rename = "bytes")]
+    data: std::mem::ManuallyDrop<Vec<u8>>,
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        unsafe {
+            ManuallyDrop::drop(&mut self.name);
+            ManuallyDrop::drop(&mut self.data);
+        }
+    }
+}
+
+fn main() {
+    let handle = Handle {
+        name: ManuallyDrop::new("handle".to_owned()),
+        data: ManuallyDrop::new(vec![1, 2, 3]),
+    };
+
+    let name: &String = handle.name();
+    assert!(name == "handle");
+    assert!(handle.bytes() == &vec![1, 2, 3]);
+}
@@ -0,0 +1,11 @@
+//! manually_drop on a field that isn't a `ManuallyDrop`.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Handle {
+    #[getter(manually_drop)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,38 @@
+//! Free functions alongside the getter methods.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(free_fn)]
+struct Point {
+    x: i64,
+    #[getter(rename = "vertical")]
+    y: i64,
+    #[getter(skip)]
+    hidden: bool,
+}
+
+#[derive(Getters)]
+#[getter(free_fn)]
+pub struct GenericPair<'a, T: Clone> {
+    #[getter(as_slice)]
+    items: Vec<T>,
+    label: &'a str,
+    #[getter(ptr_mut)]
+    count: usize,
+}
+
+fn main() {
+    let point = Point { x: 1, y: 2, hidden: true };
+    assert!(point_x(&point) == &1);
+    assert!(point_vertical(&point) == point.vertical());
+    assert!(point.hidden);
+
+    let mut pair = GenericPair { items: vec!['a', 'b'], label: "pair", count: 2 };
+    let items: &[char] = generic_pair_items(&pair);
+    assert!(items == ['a', 'b']);
+    assert!(generic_pair_label(&pair) == "pair");
+    let count = generic_pair_count(&mut pair);
+    unsafe { *count += 1; }
+    assert!(pair.count == 3);
+}
@@ -0,0 +1,38 @@
+//! Renaming to raw identifiers.
+
+use derive_getters::{Getters, Dissolve};
+
+#[derive(Dissolve)]
+#[dissolve(rename = "r#move")]
+#[dissolve(with)]
+struct Crate {
+    a: u8,
+    b: u8,
+}
+
+#[derive(Dissolve)]
+#[dissolve(rename = r#final)]
+struct Last {
+    a: u8,
+    b: u8,
+}
+
+#[derive(Getters)]
+struct Keywords {
+    #[getter(rename = "r#type")]
+    kind: String,
+}
+
+fn main() {
+    let (a, b) = Crate { a: 1, b: 2 }.r#move();
+    assert!(a == 1 && b == 2);
+
+    let sum = Crate { a: 1, b: 2 }.move_with(|a, b| a + b);
+    assert!(sum == 3);
+
+    let (a, b) = Last { a: 3, b: 4 }.r#final();
+    assert!(a == 3 && b == 4);
+
+    let keywords = Keywords { kind: "raw".to_owned() };
+    assert!(keywords.r#type() == "raw");
+}
@@ -0,0 +1,34 @@
+//! Slicing fields whose elements are themselves generic.
+
+use std::collections::HashMap;
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Matrix<T> {
+    #[getter(as_slice)]
+    data: Vec<Vec<f64>>,
+
+    #[getter(as_slice)]
+    rows: [Vec<T>; 2],
+
+    #[getter(as_slice)]
+    lookups: Box<[HashMap<String, Vec<u8>>]>,
+}
+
+fn main() {
+    let matrix = Matrix {
+        data: vec![vec![1.0, 2.0], vec![3.0, 4.0]],
+        rows: [vec!['a'], vec!['b', 'c']],
+        lookups: vec![HashMap::new()].into_boxed_slice(),
+    };
+
+    let data: &[Vec<f64>] = matrix.data();
+    assert!(data.len() == 2);
+    assert!(data[1][0] == 3.0);
+
+    let rows: &[Vec<char>] = matrix.rows();
+    assert!(rows[1] == vec!['b', 'c']);
+
+    let lookups: &[HashMap<String, Vec<u8>>] = matrix.lookups();
+    assert!(lookups[0].is_empty());
+}
@@ -0,0 +1,40 @@
+//! Replacing the where-clause of the getters impl.
+
+use derive_getters::Getters;
+
+trait Shout {
+    fn shout(&self) -> String;
+}
+
+impl Shout for String {
+    fn shout(&self) -> String {
+        self.to_uppercase()
+    }
+}
+
+// The getters need `Debug` on top of the struct's own bound.
+#[derive(Getters)]
+#[getter(override_where = "T: Shout + std::fmt::Debug")]
+struct Holder<T>
+where
+    T: Shout,
+{
+    #[getter(body = "format!(\"{:?}\", self.value)", ty = "String")]
+    value: T,
+}
+
+#[derive(Getters)]
+#[getter(override_where = "where U: Clone")]
+struct Bare<U> {
+    #[getter(body = "self.inner.clone()", ty = "U")]
+    inner: U,
+}
+
+fn main() {
+    let bare = Bare { inner: vec![1] };
+    assert!(bare.inner() == vec![1]);
+
+    let holder = Holder { value: "quiet".to_owned() };
+    assert!(holder.value() == "\"quiet\"");
+    assert!(holder.value.shout() == "QUIET");
+}
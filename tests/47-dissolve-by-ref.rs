@@ -0,0 +1,58 @@
+//! Borrowing dissolve, with and without an explicit lifetime.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(by_ref)]
+struct Plain {
+    a: u8,
+    b: String,
+}
+
+#[derive(Dissolve)]
+#[dissolve(by_ref, explicit_lifetime)]
+struct Annotated<'a, T> {
+    name: &'a str,
+    value: T,
+}
+
+#[derive(Dissolve)]
+#[dissolve(rename = "split", by_ref, explicit_lifetime)]
+struct Tuple(u8, #[dissolve(skip)] char, String);
+
+// The struct's own `'dissolve` isn't shadowed by the one the derive names.
+#[derive(Dissolve)]
+#[dissolve(by_ref, mut, explicit_lifetime)]
+struct Shadowed<'dissolve> {
+    name: &'dissolve str,
+    count: usize,
+}
+
+// The named lifetime lets the borrow outlive the call in generic code.
+fn first<'s, T>(annotated: &'s Annotated<'_, T>) -> &'s T {
+    let (_, value): (&'s &str, &'s T) = annotated.dissolve_ref();
+    value
+}
+
+fn main() {
+    let plain = Plain { a: 1, b: "b".to_owned() };
+    let (a, b) = plain.dissolve_ref();
+    assert!(*a == 1 && b == "b");
+    let (a, b) = plain.dissolve();
+    assert!(a == 1 && b == "b");
+
+    let annotated = Annotated { name: "ferris", value: 2.5 };
+    let (name, value) = annotated.dissolve_ref();
+    assert!(*name == "ferris" && *value == 2.5);
+    assert!(*first(&annotated) == 2.5);
+
+    let tuple = Tuple(1, 'x', "three".to_owned());
+    let (one, three) = tuple.split_ref();
+    assert!(*one == 1 && three == "three");
+
+    let mut shadowed = Shadowed { name: "ferris", count: 1 };
+    let (_, count) = shadowed.dissolve_mut();
+    *count += 1;
+    let (name, count) = shadowed.dissolve_ref();
+    assert!(*name == "ferris" && *count == 2);
+}
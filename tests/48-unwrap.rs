@@ -0,0 +1,26 @@
+//! Getters unwrapping `Option` and `Result` fields.
+#![deny(missing_docs)]
+
+use derive_getters::Getters;
+
+/// Configuration, always complete once loaded.
+#[derive(Getters)]
+pub struct Loaded {
+    #[getter(unwrap)]
+    name: Option<String>,
+
+    #[getter(unwrap)]
+    #[getter(rename = "port_number")]
+    port: Result<u16, std::num::ParseIntError>,
+}
+
+fn main() {
+    let loaded = Loaded { name: Some("server".to_owned()), port: "8080".parse() };
+    let name: &String = loaded.name();
+    assert!(name == "server");
+    assert!(loaded.port_number() == &8080);
+
+    let missing = Loaded { name: None, port: "x".parse() };
+    let panicked = std::panic::catch_unwind(|| missing.name().len());
+    assert!(panicked.is_err());
+}
@@ -0,0 +1,53 @@
+//! A struct level default mode for every getter with per-field overrides.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(all = "copy")]
+struct Copied {
+    x: u32,
+    y: f64,
+    #[getter(ref)]
+    label: String,
+    #[getter(as_slice)]
+    bytes: Vec<u8>,
+}
+
+#[derive(Getters)]
+#[getter(all = "clone")]
+struct Cloned<T: Clone> {
+    name: String,
+    items: Vec<T>,
+    #[getter(copy)]
+    count: usize,
+}
+
+#[derive(Getters)]
+#[getter(all = "as_ref")]
+struct Optional {
+    first: Option<String>,
+    second: Option<u8>,
+    #[getter(ref)]
+    third: u8,
+}
+
+fn main() {
+    let copied = Copied { x: 1, y: 2.0, label: "l".to_owned(), bytes: vec![3] };
+    let x: u32 = copied.x();
+    assert!(x == 1);
+    assert!(copied.y() == 2.0);
+    assert!(copied.label() == "l");
+    assert!(copied.bytes() == [3]);
+
+    let cloned = Cloned { name: "n".to_owned(), items: vec!['a'], count: 1 };
+    let name: String = cloned.name();
+    assert!(name == "n");
+    assert!(cloned.items() == vec!['a']);
+    assert!(cloned.count() == 1);
+
+    let optional = Optional { first: Some("f".to_owned()), second: None, third: 3 };
+    let first: Option<&String> = optional.first();
+    assert!(first.map(String::as_str) == Some("f"));
+    assert!(optional.second().is_none());
+    assert!(optional.third() == &3);
+}
@@ -0,0 +1,32 @@
+//! Mapped getters calling a method on the field.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Person {
+    #[getter(map = "to_uppercase", map_ty = "String")]
+    name: String,
+
+    #[getter(map = "len", map_ty = "usize")]
+    #[getter(rename = "tags")]
+    labels: Vec<String>,
+
+    #[getter(map = "is_some", map_ty = "bool")]
+    #[getter(as_ref)]
+    nickname: Option<String>,
+}
+
+fn main() {
+    let person = Person {
+        name: "ferris".to_owned(),
+        labels: vec!["crab".to_owned(), "rust".to_owned()],
+        nickname: None,
+    };
+
+    assert!(person.name() == "ferris");
+    assert!(person.name_mapped() == "FERRIS");
+    assert!(person.tags().len() == 2);
+    assert!(person.tags_mapped() == 2);
+    assert!(person.nickname().is_none());
+    assert!(!person.nickname_mapped());
+}
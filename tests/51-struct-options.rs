@@ -0,0 +1,48 @@
+//! Struct options combined in a single attribute.
+
+mod people {
+    use derive_getters::Getters;
+
+    #[derive(Getters)]
+    #[getter(vis = "pub(crate)", prefix = "get_", rename_all = "snake_case")]
+    pub struct Person {
+        first_name: String,
+
+        #[getter(rename = "surname")]
+        last_name: String,
+
+        age: u8,
+    }
+
+    impl Person {
+        pub fn new(first_name: &str, last_name: &str, age: u8) -> Self {
+            Person {
+                first_name: first_name.to_owned(),
+                last_name: last_name.to_owned(),
+                age,
+            }
+        }
+    }
+
+    #[derive(Getters)]
+    #[getter(rename_all = "camelCase", vis = "pub(super)")]
+    pub struct Account {
+        user_id: u64,
+    }
+
+    impl Account {
+        pub fn new(user_id: u64) -> Self {
+            Account { user_id }
+        }
+    }
+}
+
+fn main() {
+    let person = people::Person::new("Ada", "Lovelace", 36);
+    assert!(person.get_first_name() == "Ada");
+    assert!(person.surname() == "Lovelace");
+    assert!(*person.get_age() == 36);
+
+    let account = people::Account::new(7);
+    assert!(*account.userId() == 7);
+}
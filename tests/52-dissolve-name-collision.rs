@@ -0,0 +1,11 @@
+//! The dissolve method can't share its name with a field.
+
+use derive_getters::{Getters, Dissolve};
+
+#[derive(Getters, Dissolve)]
+struct Melt {
+    dissolve: bool,
+    rate: u64,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+//! Getters flattened from a nested struct.
+
+use derive_getters::Getters;
+
+struct Common {
+    id: u32,
+    name: String,
+    created: u64,
+}
+
+#[derive(Getters)]
+#[getter(flatten(common: [id: u32, name: String]))]
+struct Outer {
+    #[getter(rename = "shared")]
+    common: Common,
+    extra: u32,
+}
+
+fn main() {
+    let common = Common { id: 1, name: "first".to_owned(), created: 0 };
+    let outer = Outer { common, extra: 2 };
+
+    assert!(*outer.id() == 1);
+    assert!(outer.name() == "first");
+    assert!(*outer.extra() == 2);
+    assert!(outer.shared().created == 0);
+}
@@ -0,0 +1,17 @@
+//! Flattened getters can't share a name with an outer field getter.
+
+use derive_getters::Getters;
+
+struct Common {
+    id: u32,
+    extra: u32,
+}
+
+#[derive(Getters)]
+#[getter(flatten(common: [id: u32, extra: u32]))]
+struct Outer {
+    common: Common,
+    extra: u32,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+//! Strict mode points at attributes misspelling `getter`.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(strict)]
+struct Typo {
+    #[getters(skip)]
+    secret: u64,
+
+    public: u64,
+}
+
+fn main() {}
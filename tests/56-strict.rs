@@ -0,0 +1,20 @@
+//! Strict mode leaves correctly spelled and unrelated attributes alone.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(strict, prefix = "get_")]
+#[allow(dead_code)]
+struct Careful {
+    #[getter(skip)]
+    #[allow(dead_code)]
+    secret: u64,
+
+    #[doc = "Visible to everyone."]
+    public: u64,
+}
+
+fn main() {
+    let careful = Careful { secret: 1, public: 2 };
+    assert!(*careful.get_public() == 2);
+}
@@ -0,0 +1,38 @@
+//! Owned getters for borrowed fields.
+
+use std::path::{Path, PathBuf};
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Record<'a> {
+    #[getter(to_owned)]
+    name: &'a str,
+
+    #[getter(to_owned)]
+    bytes: &'a [u8],
+
+    #[getter(to_owned)]
+    path: &'a Path,
+
+    tag: &'a str,
+}
+
+fn main() {
+    let data = vec![1u8, 2, 3];
+    let record = Record {
+        name: "ferris",
+        bytes: &data,
+        path: Path::new("/tmp"),
+        tag: "crab",
+    };
+
+    let name: String = record.name();
+    let bytes: Vec<u8> = record.bytes();
+    let path: PathBuf = record.path();
+
+    assert!(name == "ferris");
+    assert!(bytes == vec![1, 2, 3]);
+    assert!(path == PathBuf::from("/tmp"));
+    assert!(record.tag() == "crab");
+}
@@ -0,0 +1,11 @@
+//! The to_owned option needs a borrowed field it can infer the owned type of.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Owned {
+    #[getter(to_owned)]
+    name: String,
+}
+
+fn main() {}
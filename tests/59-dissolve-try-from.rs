@@ -0,0 +1,31 @@
+//! Building a struct back from its dissolved tuple through the conversion traits.
+
+use std::convert::{TryFrom, TryInto};
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(try_from)]
+struct Named<T> {
+    label: String,
+    value: T,
+}
+
+#[derive(Dissolve)]
+#[dissolve(try_from, rename = "split")]
+struct Tuple(u8, u16, u32);
+
+fn main() {
+    let named: Named<u64> = ("answer".to_owned(), 42).into();
+    let (label, value) = named.dissolve();
+    assert!(label == "answer" && value == 42);
+
+    let named: Named<bool> = ("flag".to_owned(), true).try_into().unwrap();
+    assert!(named.label == "flag" && named.value);
+
+    let tuple = Tuple::try_from((1, 2, 3)).unwrap();
+    assert!(tuple.split() == (1, 2, 3));
+
+    let tuple: Tuple = Tuple::from((4, 5, 6));
+    assert!(tuple.0 == 4 && tuple.1 == 5 && tuple.2 == 6);
+}
@@ -0,0 +1,19 @@
+//! Getters with rustdoc search aliases.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Rectangle {
+    #[getter(doc_alias = "width")]
+    #[getter(doc_alias = "x")]
+    w: u64,
+
+    #[getter(doc_alias = "height")]
+    #[getter(rename = "tall")]
+    h: u64,
+}
+
+fn main() {
+    let rectangle = Rectangle { w: 3, h: 4 };
+    assert!(*rectangle.w() * *rectangle.tall() == 12);
+}
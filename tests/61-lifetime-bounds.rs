@@ -0,0 +1,34 @@
+//! Lifetime bounds between the struct's lifetimes are kept on the generated impls.
+
+use derive_getters::{Getters, Dissolve};
+
+#[derive(Getters, Dissolve)]
+struct Bounded<'a, 'b: 'a> {
+    x: &'a str,
+    y: &'b str,
+}
+
+#[derive(Getters, Dissolve)]
+#[getter(free_fn)]
+#[dissolve(by_ref, explicit_lifetime)]
+struct Nested<'a, 'b: 'a, T: 'b> {
+    outer: &'a &'b T,
+    label: &'b str,
+}
+
+fn main() {
+    let long = "long".to_owned();
+    let short = "short".to_owned();
+    let bounded = Bounded { x: &short, y: &long };
+    assert!(bounded.x() == "short");
+    assert!(bounded.y() == "long");
+    assert!(bounded.dissolve() == ("short", "long"));
+
+    let value = 7u32;
+    let inner = &value;
+    let nested = Nested { outer: &inner, label: "seven" };
+    assert!(**nested.outer() == 7);
+    assert!(nested_label(&nested) == "seven");
+    let (outer, label) = nested.dissolve_ref();
+    assert!(***outer == 7 && *label == "seven");
+}
@@ -0,0 +1,37 @@
+//! Constructors generated alongside the getters.
+
+mod shapes {
+    use derive_getters::Getters;
+
+    #[derive(Getters)]
+    #[getter(new)]
+    pub struct Labelled<T, const N: usize>
+    where
+        T: Copy,
+    {
+        label: String,
+        values: [T; N],
+
+        #[getter(skip)]
+        cached: Option<T>,
+    }
+
+    #[derive(Getters)]
+    #[getter(new, vis = "pub(crate)")]
+    pub struct Point {
+        x: i64,
+        y: i64,
+    }
+
+    pub const ORIGIN: Point = Point::new(0, 0);
+}
+
+fn main() {
+    let labelled = shapes::Labelled::new("pair".to_owned(), [1u8, 2], None);
+    assert!(labelled.label() == "pair");
+    assert!(labelled.values() == &[1, 2]);
+
+    let point = shapes::Point::new(3, 4);
+    assert!(*point.x() + *point.y() == 7);
+    assert!(*shapes::ORIGIN.x() == 0);
+}
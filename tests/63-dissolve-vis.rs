@@ -0,0 +1,34 @@
+//! Dissolve methods with restricted visibility.
+
+mod inner {
+    use derive_getters::Dissolve;
+
+    #[derive(Dissolve)]
+    #[dissolve(vis = "pub(crate)", with)]
+    pub struct Scoped {
+        a: u8,
+        b: u16,
+    }
+
+    #[derive(Dissolve)]
+    #[dissolve(vis = "")]
+    pub struct Private {
+        a: u8,
+        b: u16,
+    }
+
+    pub fn scoped() -> Scoped {
+        Scoped { a: 1, b: 2 }
+    }
+
+    pub fn private_sum() -> u16 {
+        let (a, b) = Private { a: 3, b: 4 }.dissolve();
+        u16::from(a) + b
+    }
+}
+
+fn main() {
+    assert!(inner::scoped().dissolve() == (1, 2));
+    assert!(inner::scoped().dissolve_with(|a, b| u16::from(a) + b) == 3);
+    assert!(inner::private_sum() == 7);
+}
@@ -0,0 +1,16 @@
+//! A private dissolve method can't be called from outside its module.
+
+mod inner {
+    use derive_getters::Dissolve;
+
+    #[derive(Dissolve)]
+    #[dissolve(vis = "")]
+    pub struct Private {
+        pub a: u8,
+        pub b: u16,
+    }
+}
+
+fn main() {
+    let _ = inner::Private { a: 1, b: 2 }.dissolve();
+}
@@ -0,0 +1,35 @@
+//! Getters reaching through a pinned box.
+
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+
+use derive_getters::Getters;
+
+struct Unmovable {
+    value: u64,
+    _pinned: PhantomPinned,
+}
+
+#[derive(Getters)]
+struct Pinned {
+    #[getter(pin_ref)]
+    count: Pin<Box<u64>>,
+
+    #[getter(pin_ref)]
+    unmovable: Pin<Box<Unmovable>>,
+
+    boxed: Pin<Box<u64>>,
+}
+
+fn main() {
+    let pinned = Pinned {
+        count: Box::pin(3),
+        unmovable: Box::pin(Unmovable { value: 4, _pinned: PhantomPinned }),
+        boxed: Box::pin(5),
+    };
+
+    let count: &u64 = pinned.count();
+    assert!(*count == 3);
+    assert!(pinned.unmovable().value == 4);
+    assert!(**pinned.boxed() == 5);
+}
@@ -0,0 +1,11 @@
+//! The pin_ref option needs a `Pin<Box<T>>` field.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Unpinned {
+    #[getter(pin_ref)]
+    count: Box<u64>,
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+//! Getter names built from a template.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(rename_all = "template:{}_value")]
+struct Suffixed {
+    foo: u64,
+
+    #[getter(rename = "bar")]
+    baz: u64,
+}
+
+#[derive(Getters)]
+#[getter(rename_all = "template:get_{}")]
+struct Prefixed {
+    foo: u64,
+}
+
+fn main() {
+    let suffixed = Suffixed { foo: 1, baz: 2 };
+    assert!(*suffixed.foo_value() + *suffixed.bar() == 3);
+
+    let prefixed = Prefixed { foo: 3 };
+    assert!(*prefixed.get_foo() == 3);
+}
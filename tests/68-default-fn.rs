@@ -0,0 +1,26 @@
+//! A constructor from the struct's `Default` implementation.
+
+use derive_getters::Getters;
+
+#[derive(Getters, Default)]
+#[getter(default_fn)]
+struct Settings {
+    retries: u32,
+    name: String,
+}
+
+#[derive(Getters, Default)]
+#[getter(default_fn, new)]
+struct Wrapper<T> {
+    inner: T,
+}
+
+fn main() {
+    let settings = Settings::with_defaults();
+    assert!(*settings.retries() == 0);
+    assert!(settings.name().is_empty());
+
+    let wrapper: Wrapper<Vec<u8>> = Wrapper::with_defaults();
+    assert!(wrapper.inner().is_empty());
+    assert!(*Wrapper::new(3).inner() == 3);
+}
@@ -0,0 +1,69 @@
+//! Skipped fields dropped before the dissolved tuple is built.
+
+use std::cell::RefCell;
+
+use derive_getters::Dissolve;
+
+thread_local! {
+    static EVENTS: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+}
+
+fn record(event: &'static str) {
+    EVENTS.with(|events| events.borrow_mut().push(event));
+}
+
+fn take_events() -> Vec<&'static str> {
+    EVENTS.with(|events| events.borrow_mut().drain(..).collect())
+}
+
+struct Noisy(&'static str);
+
+impl Drop for Noisy {
+    fn drop(&mut self) {
+        record(self.0);
+    }
+}
+
+struct Value(u8);
+
+impl Value {
+    fn get(self) -> u8 {
+        record("tuple");
+        self.0
+    }
+}
+
+#[derive(Dissolve)]
+#[dissolve(drop_first = [guard, lock])]
+struct Guarded {
+    value: Value,
+    #[dissolve(skip)]
+    lock: Noisy,
+    #[dissolve(skip)]
+    guard: Noisy,
+    #[dissolve(skip)]
+    other: Noisy,
+    count: u16,
+}
+
+#[derive(Dissolve)]
+#[dissolve(drop_first = [1])]
+struct Tuple(Value, #[dissolve(skip)] Noisy, u16);
+
+fn main() {
+    let guarded = Guarded {
+        value: Value(1),
+        lock: Noisy("lock"),
+        guard: Noisy("guard"),
+        other: Noisy("other"),
+        count: 2,
+    };
+    let (value, count) = guarded.dissolve();
+    assert!(value.get() == 1 && count == 2);
+    assert!(take_events() == vec!["guard", "lock", "other", "tuple"]);
+
+    let (value, count) = Tuple(Value(3), Noisy("first"), 4).dissolve();
+    record("returned");
+    assert!(value.get() == 3 && count == 4);
+    assert!(take_events() == vec!["first", "returned", "tuple"]);
+}
@@ -0,0 +1,26 @@
+//! Unit fields get no getter unless kept.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Marked {
+    value: u64,
+    marker: (),
+
+    #[getter(keep)]
+    kept: (),
+}
+
+// Would collide with a generated `marker` getter.
+impl Marked {
+    fn marker(&self) -> u64 {
+        let () = self.marker;
+        *self.value() + 1
+    }
+}
+
+fn main() {
+    let marked = Marked { value: 1, marker: (), kept: () };
+    assert!(marked.marker() == 2);
+    assert!(*marked.kept() == ());
+}
@@ -0,0 +1,31 @@
+//! Lookups into map fields.
+
+use std::collections::{BTreeMap, HashMap};
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Scores {
+    #[getter(map_access)]
+    by_name: HashMap<String, u32>,
+
+    #[getter(map_access)]
+    #[getter(rename = "ranks")]
+    by_rank: BTreeMap<u8, String>,
+}
+
+fn main() {
+    let mut by_name = HashMap::new();
+    by_name.insert("ferris".to_owned(), 10);
+    let mut by_rank = BTreeMap::new();
+    by_rank.insert(1, "ferris".to_owned());
+    let scores = Scores { by_name, by_rank };
+
+    assert!(scores.by_name().len() == 1);
+    assert!(scores.by_name_get(&"ferris".to_owned()) == Some(&10));
+    assert!(scores.by_name_get(&"corro".to_owned()).is_none());
+    assert!(scores.by_name_contains(&"ferris".to_owned()));
+    assert!(!scores.by_name_contains(&"corro".to_owned()));
+    assert!(scores.ranks_get(&1).map(String::as_str) == Some("ferris"));
+    assert!(!scores.ranks_contains(&2));
+}
@@ -0,0 +1,27 @@
+//! Getters named after the field type.
+
+use derive_getters::Getters;
+
+struct Config {
+    verbose: bool,
+}
+
+struct HttpLimits {
+    max: usize,
+}
+
+#[derive(Getters)]
+struct App<'a> {
+    #[getter(rename_from_type)]
+    settings: Config,
+
+    #[getter(rename_from_type)]
+    limits: &'a HttpLimits,
+}
+
+fn main() {
+    let limits = HttpLimits { max: 8 };
+    let app = App { settings: Config { verbose: true }, limits: &limits };
+    assert!(app.config().verbose);
+    assert!(app.http_limits().max == 8);
+}
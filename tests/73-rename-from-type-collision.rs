@@ -0,0 +1,15 @@
+//! Getters named after their type can collide with other getters.
+
+use derive_getters::Getters;
+
+struct Config;
+
+#[derive(Getters)]
+struct App {
+    config: bool,
+
+    #[getter(rename_from_type)]
+    settings: Config,
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+//! Snapshots cloning every field into a separate struct.
+
+use derive_getters::Getters;
+
+mod snapshots {
+    pub struct StateSnapshot<T> {
+        pub values: Vec<T>,
+        pub name: String,
+        pub version: u32,
+    }
+}
+
+#[derive(Getters)]
+#[getter(snapshot = "snapshots::StateSnapshot<T>")]
+struct State<T: Clone> {
+    values: Vec<T>,
+    name: String,
+
+    #[getter(skip)]
+    version: u32,
+}
+
+fn main() {
+    let mut state = State { values: vec![1u8, 2], name: "live".to_owned(), version: 1 };
+    let snapshot = state.snapshot();
+    state.values.push(3);
+    state.version += 1;
+
+    assert!(snapshot.values == vec![1, 2]);
+    assert!(snapshot.name == "live");
+    assert!(snapshot.version == 1);
+    assert!(state.values().len() == 3 && state.version == 2);
+}
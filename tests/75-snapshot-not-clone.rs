@@ -0,0 +1,19 @@
+//! Snapshots need every field to be `Clone`.
+
+use derive_getters::Getters;
+
+struct Handle;
+
+struct ResourceSnapshot {
+    handle: Handle,
+    count: u32,
+}
+
+#[derive(Getters)]
+#[getter(snapshot = "ResourceSnapshot")]
+struct Resource {
+    handle: Handle,
+    count: u32,
+}
+
+fn main() {}
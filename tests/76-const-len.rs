@@ -0,0 +1,24 @@
+//! Array lengths available in const contexts.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Keys<const N: usize> {
+    #[getter(const_len)]
+    key: [u8; 32],
+
+    #[getter(const_len)]
+    #[getter(rename = "nonces")]
+    nonce: [u8; N],
+}
+
+const KEY_LEN: usize = Keys::<12>::key_len();
+
+fn main() {
+    assert!(KEY_LEN == 32);
+    assert!(Keys::<12>::nonces_len() == 12);
+
+    let keys = Keys { key: [0; KEY_LEN], nonce: [1; 4] };
+    assert!(keys.key().len() == Keys::<4>::key_len());
+    assert!(keys.nonces().len() == 4);
+}
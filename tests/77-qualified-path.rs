@@ -0,0 +1,51 @@
+//! Fields typed by associated type projections.
+
+use derive_getters::{Getters, Dissolve};
+
+trait Storage {
+    type Item;
+    type Key: Copy;
+}
+
+struct Memory;
+
+impl Storage for Memory {
+    type Item = String;
+    type Key = u32;
+}
+
+#[derive(Getters, Dissolve)]
+struct Iterating<T: IntoIterator>
+where
+    <T as IntoIterator>::IntoIter: Clone,
+{
+    #[getter(clone)]
+    iter: <T as IntoIterator>::IntoIter,
+    count: usize,
+}
+
+#[derive(Getters, Dissolve)]
+struct Store<S: Storage> {
+    item: <S as Storage>::Item,
+
+    #[getter(copy)]
+    key: <S as Storage>::Key,
+
+    #[getter(as_ref)]
+    spare: Option<<S as Storage>::Item>,
+}
+
+fn main() {
+    let iterating: Iterating<Vec<u8>> = Iterating { iter: vec![1, 2, 3].into_iter(), count: 3 };
+    assert!(iterating.iter().sum::<u8>() == 6);
+    assert!(*iterating.count() == 3);
+    let (iter, count) = iterating.dissolve();
+    assert!(iter.len() == count);
+
+    let store: Store<Memory> = Store { item: "value".to_owned(), key: 7, spare: None };
+    assert!(store.item() == "value");
+    assert!(store.key() == 7);
+    assert!(store.spare().is_none());
+    let (item, key, spare) = store.dissolve();
+    assert!(item == "value" && key == 7 && spare.is_none());
+}
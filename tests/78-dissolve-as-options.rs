@@ -0,0 +1,34 @@
+//! Dissolved tuples with each element wrapped in `Option`.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(as_options, from_impl, reconstruct)]
+struct Staged {
+    socket: u16,
+    buffer: Vec<u8>,
+}
+
+#[derive(Dissolve)]
+#[dissolve(as_options, rename = "teardown", alias)]
+struct Pair(u8, String);
+
+fn main() {
+    let (mut socket, mut buffer) = Staged { socket: 80, buffer: vec![1, 2] }.dissolve();
+    assert!(socket.take() == Some(80));
+    assert!(socket.is_none());
+    assert!(buffer.take() == Some(vec![1, 2]));
+
+    let (socket, buffer): (Option<u16>, Option<Vec<u8>>) =
+        Staged { socket: 443, buffer: Vec::new() }.into();
+    assert!(socket == Some(443) && buffer == Some(Vec::new()));
+
+    let staged = Staged::reconstruct((8080, vec![3]));
+    assert!(staged.socket == 8080 && staged.buffer == vec![3]);
+
+    let (first, second) = Pair(1, "two".to_owned()).teardown();
+    assert!(first == Some(1) && second.as_deref() == Some("two"));
+
+    let (first, second) = Pair(3, "four".to_owned()).dissolve();
+    assert!(first == Some(3) && second == Some("four".to_owned()));
+}
@@ -0,0 +1,30 @@
+//! Several names for one getter.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Paint {
+    #[getter(rename = "color")]
+    #[getter(rename = "colour")]
+    shade: u32,
+
+    #[getter(alias = "title")]
+    #[getter(alias = heading)]
+    name: String,
+
+    #[getter(copy)]
+    #[getter(alias = "total")]
+    count: usize,
+}
+
+fn main() {
+    let paint = Paint { shade: 0xff0000, name: "red".to_owned(), count: 3 };
+
+    assert!(std::ptr::eq(paint.color(), paint.colour()));
+    assert!(*paint.colour() == 0xff0000);
+
+    assert!(std::ptr::eq(paint.name(), paint.title()));
+    assert!(std::ptr::eq(paint.name(), paint.heading()));
+
+    assert!(paint.count() == 3 && paint.total() == 3);
+}
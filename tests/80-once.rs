@@ -0,0 +1,31 @@
+//! Getters for lazily initialised cells.
+
+use std::cell::OnceCell;
+use std::sync::OnceLock;
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Lazy {
+    #[getter(once)]
+    greeting: OnceCell<String>,
+
+    #[getter(once)]
+    shared: OnceLock<Vec<u8>>,
+
+    count: u64,
+}
+
+fn main() {
+    let lazy = Lazy { greeting: OnceCell::new(), shared: OnceLock::new(), count: 1 };
+    assert!(lazy.greeting().is_none());
+    assert!(lazy.shared().is_none());
+
+    lazy.greeting.get_or_init(|| "hello".to_owned());
+    assert!(lazy.greeting() == Some(&"hello".to_owned()));
+    assert!(std::ptr::eq(lazy.greeting().unwrap(), lazy.greeting.get().unwrap()));
+
+    lazy.shared.set(vec![1, 2]).unwrap();
+    assert!(lazy.shared().map(Vec::len) == Some(2));
+    assert!(*lazy.count() == 1);
+}
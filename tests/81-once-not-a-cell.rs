@@ -0,0 +1,11 @@
+//! The once option needs a `OnceCell<T>` or `OnceLock<T>` field.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Eager {
+    #[getter(once)]
+    greeting: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+//! Getters returning a trait object reference.
+
+use std::fmt::Debug;
+
+use derive_getters::Getters;
+
+trait Handler {
+    fn handle(&self, request: u8) -> u8;
+}
+
+#[derive(Debug)]
+struct Doubler;
+
+impl Handler for Doubler {
+    fn handle(&self, request: u8) -> u8 {
+        request * 2
+    }
+}
+
+#[derive(Getters)]
+struct Server<T: Handler + Send> {
+    #[getter(as_dyn = "dyn Handler")]
+    handler: Doubler,
+
+    #[getter(as_dyn = "dyn Handler + Send")]
+    generic: T,
+
+    #[getter(as_dyn = "dyn Debug")]
+    name: String,
+}
+
+fn main() {
+    let server = Server { handler: Doubler, generic: Doubler, name: "echo".to_owned() };
+
+    let handler: &dyn Handler = server.handler();
+    assert!(handler.handle(2) == 4);
+    assert!(server.generic().handle(3) == 6);
+    assert!(format!("{:?}", server.name()) == "\"echo\"");
+}
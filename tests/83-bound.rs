@@ -0,0 +1,46 @@
+//! Getters only generated when their bounds hold.
+
+use std::hash::Hash;
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Cache<T> {
+    #[getter(bound = "T: Hash")]
+    items: Vec<T>,
+
+    #[getter(bound = "T: Ord")]
+    sorted: Vec<T>,
+
+    #[getter(bound = "T: Hash")]
+    #[getter(copy)]
+    hashed: u64,
+
+    hits: usize,
+}
+
+#[derive(Getters)]
+#[getter(free_fn)]
+struct Pair<T: Clone> where T: Default {
+    #[getter(bound = "T: Copy")]
+    value: T,
+}
+
+/// Neither `Hash` nor `Ord`.
+struct Opaque;
+
+fn main() {
+    let cache = Cache { items: vec![1u8], sorted: vec![2], hashed: 3, hits: 4 };
+    assert!(cache.items() == &vec![1]);
+    assert!(cache.sorted() == &vec![2]);
+    assert!(cache.hashed() == 3);
+    assert!(*cache.hits() == 4);
+
+    // Only the unbounded getter exists for a `T` without the bounds.
+    let opaque = Cache { items: vec![Opaque], sorted: Vec::new(), hashed: 0, hits: 5 };
+    assert!(*opaque.hits() == 5);
+    assert!(opaque.items.len() == 1);
+
+    let pair = Pair { value: 6u8 };
+    assert!(*pair.value() == 6 && *pair_value(&pair) == 6);
+}
@@ -0,0 +1,18 @@
+//! A bounded getter doesn't exist when its bounds don't hold.
+
+use std::hash::Hash;
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Cache<T> {
+    #[getter(bound = "T: Hash")]
+    items: Vec<T>,
+}
+
+struct Opaque;
+
+fn main() {
+    let cache = Cache { items: vec![Opaque] };
+    let _ = cache.items();
+}
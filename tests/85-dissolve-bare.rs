@@ -0,0 +1,16 @@
+//! A bare `#[dissolve]` attribute changes nothing.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve]
+struct Pair {
+    #[dissolve]
+    left: u8,
+    right: String,
+}
+
+fn main() {
+    let (left, right) = Pair { left: 1, right: "two".to_owned() }.dissolve();
+    assert!(left == 1 && right == "two");
+}
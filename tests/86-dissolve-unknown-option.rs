@@ -0,0 +1,12 @@
+//! Unknown dissolve options are reported by name, with the closest known option.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(from_impll)]
+struct Pair {
+    left: u8,
+    right: String,
+}
+
+fn main() {}
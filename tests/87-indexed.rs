@@ -0,0 +1,32 @@
+//! Getters for elements by index, `None` when out of bounds.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Queue<'a> {
+    #[getter(indexed)]
+    jobs: Vec<String>,
+
+    #[getter(indexed)]
+    #[getter(rename = "bytes")]
+    raw: &'a [u8],
+
+    #[getter(indexed)]
+    slots: [u16; 2],
+}
+
+fn main() {
+    let raw = [7u8, 8];
+    let queue = Queue { jobs: vec!["build".to_owned(), "test".to_owned()], raw: &raw, slots: [1, 2] };
+
+    assert!(queue.jobs_at(0).map(String::as_str) == Some("build"));
+    assert!(queue.jobs_at(1).map(String::as_str) == Some("test"));
+    assert!(queue.jobs_at(2).is_none());
+    assert!(std::ptr::eq(queue.jobs_at(1).unwrap(), &queue.jobs()[1]));
+
+    assert!(queue.bytes_at(1) == Some(&8));
+    assert!(queue.bytes_at(usize::MAX).is_none());
+    assert!(queue.bytes().len() == 2);
+
+    assert!(queue.slots_at(0) == Some(&1) && queue.slots_at(2).is_none());
+}
@@ -0,0 +1,35 @@
+//! Listing the names of the fields, with and without the skipped ones.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(field_names)]
+struct Login {
+    user: String,
+    #[getter(skip)]
+    password: String,
+    #[getter(rename = "when")]
+    last_seen: u64,
+}
+
+#[derive(Getters)]
+#[getter(field_names = "all", prefix = "get_")]
+struct Audit {
+    user: String,
+    #[getter(skip)]
+    password: String,
+    r#type: u8,
+}
+
+const LOGIN_FIELDS: &[&str] = Login::field_names();
+
+fn main() {
+    assert!(LOGIN_FIELDS == ["user", "last_seen"]);
+    assert!(Audit::field_names() == ["user", "password", "type"]);
+
+    let login = Login { user: "ferris".to_owned(), password: "crab".to_owned(), last_seen: 1 };
+    assert!(login.user() == "ferris" && *login.when() == 1 && login.password.len() == 4);
+
+    let audit = Audit { user: "ferris".to_owned(), password: "crab".to_owned(), r#type: 2 };
+    assert!(audit.get_user() == "ferris" && *audit.get_type() == 2 && audit.password.len() == 4);
+}
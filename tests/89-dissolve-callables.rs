@@ -0,0 +1,40 @@
+//! Dissolving fields of trait object, fn pointer and generic closure types.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(from_impl, reconstruct)]
+struct Callbacks<F: Fn(u32) -> u32> {
+    boxed: Box<dyn Fn() -> u32>,
+    sendable: Box<dyn Fn(u32) -> u32 + Send + 'static>,
+    pointer: fn(u32) -> u32,
+    generic: F,
+    count: u32,
+}
+
+fn double(x: u32) -> u32 {
+    x * 2
+}
+
+fn main() {
+    let offset = 10;
+    let callbacks = Callbacks {
+        boxed: Box::new(|| 1),
+        sendable: Box::new(|x| x + 1),
+        pointer: double,
+        generic: move |x| x + offset,
+        count: 3,
+    };
+
+    let (boxed, sendable, pointer, generic, count) = callbacks.dissolve();
+    assert!(boxed() == 1);
+    assert!(sendable(1) == 2);
+    assert!(pointer(4) == 8);
+    assert!(generic(5) == 15);
+    assert!(count == 3);
+
+    let rebuilt = Callbacks::reconstruct((boxed, sendable, pointer, generic, count));
+    let tuple: (Box<dyn Fn() -> u32>, Box<dyn Fn(u32) -> u32 + Send>, fn(u32) -> u32, _, u32) =
+        rebuilt.into();
+    assert!((tuple.3)(1) == 11);
+}
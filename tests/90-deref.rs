@@ -0,0 +1,72 @@
+//! Getters returning the target of a newtype's `Deref` impl.
+
+use std::ops::Deref;
+
+use derive_getters::Getters;
+
+struct Name(String);
+
+impl Deref for Name {
+    type Target = String;
+
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+struct Chain(Name);
+
+impl Deref for Chain {
+    type Target = Name;
+
+    fn deref(&self) -> &Name {
+        &self.0
+    }
+}
+
+struct Wrapper<T>(Vec<T>);
+
+impl<T> Deref for Wrapper<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+#[derive(Getters)]
+struct User<T> {
+    #[getter(deref = "String")]
+    name: Name,
+
+    #[getter(deref = "Name")]
+    alias: Chain,
+
+    #[getter(deref = "[T]")]
+    items: Wrapper<T>,
+
+    #[getter(deref = "str")]
+    boxed: Box<str>,
+}
+
+fn main() {
+    let user = User {
+        name: Name("ferris".to_owned()),
+        alias: Chain(Name("crab".to_owned())),
+        items: Wrapper(vec![1u8, 2]),
+        boxed: "boxed".into(),
+    };
+
+    let name: &String = user.name();
+    assert!(name == "ferris");
+    assert!(std::ptr::eq(name, &user.name.0));
+
+    let alias: &Name = user.alias();
+    assert!(alias.as_str() == "crab");
+
+    let items: &[u8] = user.items();
+    assert!(items == [1, 2]);
+
+    let boxed: &str = user.boxed();
+    assert!(boxed == "boxed");
+}
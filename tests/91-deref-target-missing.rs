@@ -0,0 +1,23 @@
+//! The deref option needs the target type.
+
+use std::ops::Deref;
+
+use derive_getters::Getters;
+
+struct Name(String);
+
+impl Deref for Name {
+    type Target = String;
+
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+#[derive(Getters)]
+struct User {
+    #[getter(deref)]
+    name: Name,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+//! A compile time check of the dissolved tuple's arity.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(stable_arity = 2)]
+struct Pair {
+    left: u8,
+    #[dissolve(skip)]
+    _cache: u64,
+    right: String,
+}
+
+#[derive(Dissolve)]
+#[dissolve(stable_arity = 3)]
+struct Triple<T>(T, T, T);
+
+fn main() {
+    let (left, right) = Pair { left: 1, _cache: 0, right: "two".to_owned() }.dissolve();
+    assert!(left == 1 && right == "two");
+
+    let (a, b, c) = Triple(1, 2, 3).dissolve();
+    assert!(a + b + c == 6);
+}
@@ -0,0 +1,13 @@
+//! Adding a field breaks the build at the stated arity.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(stable_arity = 2)]
+struct Pair {
+    left: u8,
+    right: String,
+    added: bool,
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+//! Helpers comparing fields with a value.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(eq_all)]
+struct Point<T> {
+    x: i32,
+    label: String,
+    #[getter(rename = "tag")]
+    marker: T,
+    #[getter(skip)]
+    _cache: u64,
+}
+
+/// Not comparable, so its helper exists but can't be called.
+struct Opaque;
+
+#[derive(Getters)]
+struct Named {
+    #[getter(eq)]
+    name: String,
+    count: u8,
+}
+
+fn main() {
+    let point = Point { x: 1, label: "origin".to_owned(), marker: 'o', _cache: 0 };
+    assert!(point.x_eq(&1));
+    assert!(!point.x_eq(&2));
+    assert!(point.label_eq("origin"));
+    assert!(point.label_eq(&"origin".to_owned()));
+    assert!(point.tag_eq(&'o'));
+
+    let opaque = Point { x: 0, label: String::new(), marker: Opaque, _cache: 0 };
+    assert!(opaque.x_eq(&0) && opaque.label_eq(""));
+
+    let named = Named { name: "ferris".to_owned(), count: 1 };
+    assert!(named.name_eq("ferris") && *named.count() == 1);
+}
@@ -0,0 +1,55 @@
+//! Getters upgrading weak references.
+
+use std::rc::{self, Rc};
+use std::sync::{Arc, Weak};
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Child {
+    #[getter(upgrade)]
+    parent: Weak<u32>,
+
+    #[getter(upgrade)]
+    local: rc::Weak<String>,
+
+    #[getter(upgrade = "arc")]
+    qualified: std::sync::Weak<u8>,
+}
+
+mod shared {
+    use std::rc::Weak;
+
+    use derive_getters::Getters;
+
+    #[derive(Getters)]
+    pub struct Node {
+        #[getter(upgrade = "rc")]
+        pub next: Weak<u16>,
+    }
+}
+
+fn main() {
+    let parent = Arc::new(7u32);
+    let local = Rc::new("local".to_owned());
+    let qualified = Arc::new(1u8);
+    let child = Child {
+        parent: Arc::downgrade(&parent),
+        local: Rc::downgrade(&local),
+        qualified: Arc::downgrade(&qualified),
+    };
+
+    let upgraded: Option<Arc<u32>> = child.parent();
+    assert!(upgraded.as_deref() == Some(&7));
+    assert!(Arc::ptr_eq(&upgraded.unwrap(), &parent));
+    assert!(child.local().as_deref().map(String::as_str) == Some("local"));
+    assert!(child.qualified().as_deref() == Some(&1));
+
+    drop(parent);
+    assert!(child.parent().is_none());
+
+    let next = Rc::new(2u16);
+    let node = shared::Node { next: Rc::downgrade(&next) };
+    let upgraded: Option<Rc<u16>> = node.next();
+    assert!(upgraded.as_deref() == Some(&2));
+}
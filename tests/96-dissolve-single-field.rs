@@ -0,0 +1,40 @@
+//! Dissolving a single field struct into a one-tuple `(T,)`.
+
+use derive_getters::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(from_impl, reconstruct, with, by_ref)]
+struct Number {
+    num: u64,
+}
+
+#[derive(Dissolve)]
+#[dissolve(as_options)]
+struct Wrapped(String);
+
+#[derive(Dissolve)]
+struct Partial<'a> {
+    name: &'a str,
+    #[dissolve(skip)]
+    _cache: u8,
+}
+
+fn main() {
+    let tuple: (u64,) = Number { num: 64 }.dissolve();
+    assert!(tuple == (64,));
+
+    let (num,) = Number { num: 1 }.dissolve();
+    assert!(num == 1);
+
+    let (num,): (u64,) = Number { num: 2 }.into();
+    assert!(num == 2);
+    assert!(Number::reconstruct((3,)).num == 3);
+    assert!(Number { num: 4 }.dissolve_with(|num| num * 2) == 8);
+    assert!(Number { num: 5 }.dissolve_ref() == (&5,));
+
+    let (inner,) = Wrapped("inner".to_owned()).dissolve();
+    assert!(inner.as_deref() == Some("inner"));
+
+    let (name,) = Partial { name: "ferris", _cache: 0 }.dissolve();
+    assert!(name == "ferris");
+}
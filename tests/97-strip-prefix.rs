@@ -0,0 +1,38 @@
+//! Getters named without the prefix the fields share.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(strip_prefix = "user_")]
+struct User {
+    user_name: String,
+    user_email: String,
+    user_type: u8,
+    #[getter(rename = "user_key")]
+    user_id: u64,
+    created: u64,
+}
+
+#[derive(Getters)]
+#[getter(strip_prefix = "raw_", rename_all = "camelCase")]
+struct Raw {
+    raw_byte_count: usize,
+}
+
+fn main() {
+    let user = User {
+        user_name: "ferris".to_owned(),
+        user_email: "ferris@example.com".to_owned(),
+        user_type: 1,
+        user_id: 2,
+        created: 3,
+    };
+    assert!(user.name() == "ferris");
+    assert!(user.email() == "ferris@example.com");
+    assert!(*user.r#type() == 1);
+    assert!(*user.user_key() == 2);
+    assert!(*user.created() == 3);
+
+    let raw = Raw { raw_byte_count: 4 };
+    assert!(*raw.byteCount() == 4);
+}
@@ -0,0 +1,12 @@
+//! Stripping a prefix can make two getters share a name.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(strip_prefix = "user_")]
+struct User {
+    name: String,
+    user_name: String,
+}
+
+fn main() {}
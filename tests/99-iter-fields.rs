@@ -0,0 +1,32 @@
+//! Iterating over the fields of a struct whose fields share a type.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getter(iter_fields)]
+struct Rgb {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+#[derive(Getters)]
+#[getter(iter_fields)]
+struct Names<'a> {
+    first: &'a str,
+    #[getter(skip)]
+    middle: &'a str,
+    last: &'a str,
+}
+
+fn main() {
+    let rgb = Rgb { r: 1, g: 2, b: 3 };
+    let channels: Vec<&u8> = rgb.iter_fields().collect();
+    assert!(channels == [&1, &2, &3]);
+    assert!(std::ptr::eq(channels[1], rgb.g()));
+    assert!(rgb.iter_fields().copied().sum::<u8>() == 6);
+
+    let names = Names { first: "Ada", middle: "King", last: "Lovelace" };
+    let joined: Vec<&str> = names.iter_fields().copied().collect();
+    assert!(joined == ["Ada", "King", "Lovelace"]);
+}
@@ -9,6 +9,147 @@ fn tests() {
     t.pass("tests/06-plays-with-others.rs");
     t.pass("tests/07-dissolve-basic.rs");
     t.pass("tests/08-dissolve-generic-and-ref.rs");    
+    t.pass("tests/09-as-slice.rs");
+    t.compile_fail("tests/10-unknown-option-rename.rs");
+    t.compile_fail("tests/11-unknown-option-skip.rs");
+    t.pass("tests/12-cell-get.rs");
+    t.compile_fail("tests/13-cell-get-not-cell.rs");
+    t.pass("tests/14-dissolve-from-impl.rs");
+    t.pass("tests/15-rename-ident.rs");
+    t.pass("tests/16-group.rs");
+    t.compile_fail("tests/17-group-missing-field.rs");
+    t.pass("tests/18-try-into.rs");
+    t.compile_fail("tests/19-try-into-conflict.rs");
+    t.pass("tests/20-expr.rs");
+    t.pass("tests/21-non-exhaustive.rs");
+    t.pass("tests/22-body.rs");
+    t.pass("tests/23-all-skipped.rs");
+    t.pass("tests/24-dissolve-with.rs");
+    t.pass("tests/25-lock.rs");
+    t.compile_fail("tests/26-lock-not-mutex.rs");
+    t.pass("tests/27-dissolve-reconstruct.rs");
+    t.pass("tests/28-iter.rs");
+    t.pass("tests/29-dissolve-alias.rs");
+    t.pass("tests/30-const-generic-where.rs");
+    t.pass("tests/31-getters-owned.rs");
+    t.compile_fail("tests/32-getters-owned-not-copy.rs");
+    t.pass("tests/33-doc-examples.rs");
+    t.pass("tests/34-dissolve-tuple-skip.rs");
+    t.compile_fail("tests/35-rename-collision.rs");
+    t.pass("tests/36-ptr.rs");
+    t.pass("tests/37-dissolve-into-parts.rs");
+    t.pass("tests/38-skip-reason.rs");
+    t.pass("tests/39-cfg.rs");
+    t.pass("tests/40-repr.rs");
+    t.pass("tests/41-manually-drop.rs");
+    t.compile_fail("tests/42-manually-drop-not-manually-drop.rs");
+    t.pass("tests/43-free-fn.rs");
+    t.pass("tests/44-raw-rename.rs");
+    t.pass("tests/45-nested-as-slice.rs");
+    t.pass("tests/46-override-where.rs");
+    t.pass("tests/47-dissolve-by-ref.rs");
+    t.pass("tests/48-unwrap.rs");
+    t.pass("tests/49-default-mode.rs");
+    t.pass("tests/50-map.rs");
+    t.pass("tests/51-struct-options.rs");
+    t.compile_fail("tests/52-dissolve-name-collision.rs");
+    t.pass("tests/53-flatten.rs");
+    t.compile_fail("tests/54-flatten-collision.rs");
+    t.compile_fail("tests/55-strict-misspelled.rs");
+    t.pass("tests/56-strict.rs");
+    t.pass("tests/57-to-owned.rs");
+    t.compile_fail("tests/58-to-owned-not-borrowed.rs");
+    t.pass("tests/59-dissolve-try-from.rs");
+    t.pass("tests/60-doc-alias.rs");
+    t.pass("tests/61-lifetime-bounds.rs");
+    t.pass("tests/62-new.rs");
+    t.pass("tests/63-dissolve-vis.rs");
+    t.compile_fail("tests/64-dissolve-private.rs");
+    t.pass("tests/65-pin-ref.rs");
+    t.compile_fail("tests/66-pin-ref-not-pinned.rs");
+    t.pass("tests/67-rename-template.rs");
+    t.pass("tests/68-default-fn.rs");
+    t.pass("tests/69-dissolve-drop-first.rs");
+    t.pass("tests/70-unit-field.rs");
+    t.pass("tests/71-map-access.rs");
+    t.pass("tests/72-rename-from-type.rs");
+    t.compile_fail("tests/73-rename-from-type-collision.rs");
+    t.pass("tests/74-snapshot.rs");
+    t.compile_fail("tests/75-snapshot-not-clone.rs");
+    t.pass("tests/76-const-len.rs");
+    t.pass("tests/77-qualified-path.rs");
+    t.pass("tests/78-dissolve-as-options.rs");
+    t.pass("tests/79-alias.rs");
+    t.pass("tests/80-once.rs");
+    t.compile_fail("tests/81-once-not-a-cell.rs");
+    t.pass("tests/82-as-dyn.rs");
+    t.pass("tests/83-bound.rs");
+    t.compile_fail("tests/84-bound-unmet.rs");
+    t.pass("tests/85-dissolve-bare.rs");
+    t.compile_fail("tests/86-dissolve-unknown-option.rs");
+    t.pass("tests/87-indexed.rs");
+    t.pass("tests/88-field-names.rs");
+    t.pass("tests/89-dissolve-callables.rs");
+    t.pass("tests/90-deref.rs");
+    t.compile_fail("tests/91-deref-target-missing.rs");
+    t.pass("tests/92-dissolve-stable-arity.rs");
+    t.compile_fail("tests/93-dissolve-arity-changed.rs");
+    t.pass("tests/94-eq.rs");
+    t.pass("tests/95-upgrade.rs");
+    t.pass("tests/96-dissolve-single-field.rs");
+    t.pass("tests/97-strip-prefix.rs");
+    t.compile_fail("tests/98-strip-prefix-collision.rs");
+    t.pass("tests/99-iter-fields.rs");
+    t.compile_fail("tests/100-iter-fields-mixed.rs");
+    t.pass("tests/101-unsized-generics.rs");
+    t.pass("tests/102-positional-names.rs");
+    t.compile_fail("tests/103-positional-names-count.rs");
+    t.pass("tests/104-by-value-self.rs");
+    t.compile_fail("tests/105-by-value-self-not-copy.rs");
+    t.pass("tests/106-dissolve-into-variant.rs");
+    t.compile_fail("tests/107-dissolve-into-variant-path.rs");
+    t.compile_fail("tests/108-order-duplicate.rs");
+    t.pass("tests/109-replace.rs");
+    t.pass("tests/110-take.rs");
+    t.pass("tests/111-as-mut.rs");
+    t.compile_fail("tests/112-as-mut-fields.rs");
+    t.pass("tests/113-hash.rs");
+    t.compile_fail("tests/114-hash-unhashable.rs");
+    t.pass("tests/115-gat-lifetime.rs");
+    t.pass("tests/116-array-full.rs");
+    t.pass("tests/117-dissolve-group-tail.rs");
+    t.pass("tests/118-merge-bounds.rs");
+    t.pass("tests/119-result-split.rs");
+    t.compile_fail("tests/120-dissolve-field-mismatch.rs");
+    t.pass("tests/121-rename-pascal-fields.rs");
+    t.pass("tests/122-dissolve-mut.rs");
+    t.pass("tests/123-getters-dissolve.rs");
+    t.compile_fail("tests/124-getters-dissolve-errors.rs");
+    t.compile_fail("tests/125-deprecated-field.rs");
+    t.pass("tests/126-single-variant-enum.rs");
+    t.compile_fail("tests/127-enum-variants.rs");
+    t.pass("tests/128-consume.rs");
+    t.pass("tests/129-dissolve-with-names.rs");
+    t.compile_fail("tests/130-dissolve-alias-without-rename.rs");
+    t.compile_fail("tests/131-dissolve-into-parts-duplicate.rs");
+    t.pass("tests/132-strict-setters.rs");
+    t.compile_fail("tests/133-no-std-options.rs");
+    t.pass("tests/134-deprecated-generated.rs");
+    #[cfg(feature = "tracing")]
+    t.pass("tests/135-dissolve-trace.rs");
+    t.compile_fail("tests/136-dissolve-lifetime-without-borrow.rs");
+}
+
+// The `no_std` crate is built by its own cargo, with a target directory of its own so as
+// not to wait on the lock of this one.
+#[test]
+fn no_std_build() {
+    let status = std::process::Command::new(env!("CARGO"))
+        .args(["build", "--manifest-path", "tests/no-std/Cargo.toml"])
+        .env("CARGO_TARGET_DIR", env!("CARGO_TARGET_TMPDIR"))
+        .status()
+        .expect("cargo should run");
+    assert!(status.success());
 }
 
 #[test]
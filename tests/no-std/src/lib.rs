@@ -0,0 +1,50 @@
+//! Derives on a `no_std` crate. Only options whose generated code names `std` need it,
+//! eg; `lock`, `hash`, `upgrade` and `to_owned`, so those are left out here.
+#![no_std]
+
+use core::cell::Cell;
+
+use derive_getters::{AsMut, Dissolve, Getters, GettersOwned};
+
+#[derive(Getters, Dissolve)]
+#[dissolve(from_impl, reconstruct, by_ref)]
+#[getter(new, field_names)]
+pub struct Packet<'a, T: Default> {
+    id: u32,
+    #[getter(as_slice)]
+    payload: [u8; 4],
+    #[getter(copy)]
+    flags: u8,
+    #[getter(cell_get)]
+    hits: Cell<u16>,
+    #[getter(as_ref)]
+    label: Option<&'a str>,
+    #[getter(eq)]
+    #[getter(replace)]
+    #[getter(take)]
+    inner: T,
+}
+
+#[derive(Getters, Dissolve)]
+#[getter(positional_names = ["x", "y"], by_value_self)]
+#[dissolve(group_tail(2))]
+#[derive(Clone, Copy)]
+pub struct Point(i32, i32);
+
+#[derive(GettersOwned)]
+pub struct Size {
+    width: u16,
+    height: u16,
+}
+
+#[derive(AsMut)]
+pub struct Meters(f32);
+
+pub fn use_all(packet: Packet<'_, u8>, point: Point, size: &Size, meters: &mut Meters) -> u32 {
+    *meters.as_mut() += 1.0;
+    let sum = packet.id() + u32::from(packet.flags()) + u32::from(packet.hits());
+    let names = Packet::<'_, u8>::field_names().len() as u32;
+    let (id, _, _, _, _, _) = packet.dissolve();
+    let ((x, y),) = point.dissolve();
+    sum + id + names + (x + y + point.x()) as u32 + u32::from(size.width() + size.height())
+}
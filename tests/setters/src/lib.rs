@@ -0,0 +1,10 @@
+//! A `Setters` derive generating nothing, so its attributes can sit next to `getter`.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+
+#[proc_macro_derive(Setters, attributes(setter, setters))]
+pub fn setters(_input: TokenStream) -> TokenStream {
+    TokenStream::new()
+}